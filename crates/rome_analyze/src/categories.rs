@@ -28,6 +28,12 @@ pub enum ActionCategory {
     QuickFix,
     /// This action provides an optional refactor opportunity
     Refactor,
+    /// This action suppresses the diagnostic emitted by the same signal with
+    /// a `rome-ignore` comment
+    Suppression,
+    /// This action suppresses all the diagnostics emitted by the rule in the
+    /// file with a `rome-ignore-all` comment at the top of the file
+    FileSuppression,
 }
 
 bitflags! {
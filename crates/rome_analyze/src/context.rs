@@ -1,8 +1,11 @@
 use crate::options::OptionsDeserializationDiagnostic;
 use crate::{
-    registry::RuleRoot, AnalyzerOptions, FromServices, Queryable, Rule, RuleKey, ServiceBag,
+    registry::{RuleLanguage, RuleRoot},
+    AnalyzerOptions, FileKind, FromServices, Queryable, Rule, RuleKey, ServiceBag,
 };
+use rome_diagnostics::file::{FileId, FileSpan};
 use rome_diagnostics::v2::{Error, Result};
+use rome_rowan::{AstNode, BatchMutation, NodeOrToken, SyntaxToken, TextRange, TriviaPieceKind};
 use std::ops::Deref;
 
 type RuleQueryResult<R> = <<R as Rule>::Query as Queryable>::Output;
@@ -12,9 +15,12 @@ pub struct RuleContext<'a, R>
 where
     R: ?Sized + Rule,
 {
+    file_id: FileId,
+    file_kind: FileKind,
     query_result: &'a RuleQueryResult<R>,
     root: &'a RuleRoot<R>,
     services: RuleServiceBag<R>,
+    bag: &'a ServiceBag,
     options: Option<R::Options>,
 }
 
@@ -23,12 +29,14 @@ where
     R: Rule + Sized,
 {
     pub fn new(
+        file_id: FileId,
         query_result: &'a RuleQueryResult<R>,
         root: &'a RuleRoot<R>,
-        services: &ServiceBag,
+        services: &'a ServiceBag,
         options: &'a AnalyzerOptions,
     ) -> Result<Self, Error> {
         let rule_key = RuleKey::rule::<R>();
+        let file_kind = options.file_kind;
         let options = options.configuration.rules.get_rule(&rule_key);
         let options = if let Some(options) = options {
             let value = options.value();
@@ -45,13 +53,86 @@ where
         };
 
         Ok(Self {
+            file_id,
+            file_kind,
             query_result,
             root,
             services: FromServices::from_services(&rule_key, services)?,
+            bag: services,
             options,
         })
     }
 
+    /// Returns the ID of the file being analyzed
+    pub fn file_id(&self) -> FileId {
+        self.file_id
+    }
+
+    /// Returns the [kind](FileKind) of the file being analyzed
+    pub fn file_kind(&self) -> FileKind {
+        self.file_kind
+    }
+
+    /// Returns `true` if the file being analyzed is a [test file](FileKind::Test), for
+    /// rules that are relaxed in tests
+    pub fn is_test_file(&self) -> bool {
+        self.file_kind.is_test()
+    }
+
+    /// Returns the source type of the file being analyzed if the analyzer of the
+    /// language registered a source type of type `T` in the services
+    pub fn source_type<T: 'static + Clone>(&self) -> Option<T> {
+        self.bag.get_service::<T>()
+    }
+
+    /// Returns the span of `range` in the file being analyzed, to be used as a
+    /// [file label](crate::RuleDiagnostic::file_label) alongside the spans of other files
+    pub fn file_span(&self, range: TextRange) -> FileSpan {
+        FileSpan {
+            file: self.file_id,
+            range,
+        }
+    }
+
+    /// Returns the source text of `range` in the file being analyzed
+    ///
+    /// ## Panics
+    ///
+    /// If `range` is out of the bounds of the file or doesn't fall on character boundaries
+    pub fn text_of(&self, range: TextRange) -> String {
+        // Only collect the text of the smallest element covering the range,
+        // instead of the text of the whole file
+        match self.root.syntax().covering_element(range) {
+            NodeOrToken::Node(node) => {
+                let start = node.text_range().start();
+                node.text().slice(range - start).to_string()
+            }
+            NodeOrToken::Token(token) => {
+                let start = token.text_range().start();
+                token.text()[range - start].to_string()
+            }
+        }
+    }
+
+    /// Returns a [BatchMutation] replacing the leading and trailing trivia of `token`
+    /// with `new_leading` and `new_trailing`, preserving the text of the token.
+    ///
+    /// This is useful for rules that only change whitespace or comments
+    pub fn trivia_mutation(
+        &self,
+        token: SyntaxToken<RuleLanguage<R>>,
+        new_leading: &[(TriviaPieceKind, &str)],
+        new_trailing: &[(TriviaPieceKind, &str)],
+    ) -> BatchMutation<RuleLanguage<R>> {
+        let next_token = token
+            .with_leading_trivia(new_leading.iter().copied())
+            .with_trailing_trivia(new_trailing.iter().copied());
+
+        let mut mutation = BatchMutation::new(self.root.syntax().clone());
+        mutation.replace_token_discard_trivia(token, next_token);
+        mutation
+    }
+
     pub fn query(&self) -> &RuleQueryResult<R> {
         self.query_result
     }
@@ -113,3 +194,184 @@ where
         &self.services
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rome_console::markup;
+    use rome_diagnostics::file::FileId;
+    use rome_diagnostics::Applicability;
+    use rome_rowan::raw_language::{
+        RawLanguage, RawLanguageKind, RawLanguageRoot, RawSyntaxTreeBuilder,
+    };
+    use rome_rowan::{AstNode, SyntaxToken, TextRange, TextSize, TriviaPiece, TriviaPieceKind};
+
+    use super::RuleContext;
+    use crate::{
+        ActionCategory, AnalyzerOptions, Ast, FileKind, GroupCategory, RegistryVisitor, Rule,
+        RuleAction, RuleCategory, RuleGroup, RuleMeta, RuleMetadata, ServiceBag,
+    };
+
+    enum TestCategory {}
+
+    impl GroupCategory for TestCategory {
+        type Language = RawLanguage;
+        const CATEGORY: RuleCategory = RuleCategory::Lint;
+
+        fn record_groups<V: RegistryVisitor<Self::Language> + ?Sized>(_: &mut V) {}
+    }
+
+    enum TestGroup {}
+
+    impl RuleGroup for TestGroup {
+        type Language = RawLanguage;
+        type Category = TestCategory;
+        const NAME: &'static str = "test";
+
+        fn record_rules<V: RegistryVisitor<Self::Language> + ?Sized>(_: &mut V) {}
+    }
+
+    /// Signals the test files
+    enum NoTestFile {}
+
+    impl RuleMeta for NoTestFile {
+        type Group = TestGroup;
+        const METADATA: RuleMetadata = RuleMetadata::new("0.0.0", "noTestFile", "");
+    }
+
+    impl Rule for NoTestFile {
+        type Query = Ast<RawLanguageRoot>;
+        type State = ();
+        type Signals = Option<()>;
+        type Options = ();
+
+        fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+            ctx.is_test_file().then_some(())
+        }
+    }
+
+    /// Moves the leading comment of the first token after it
+    enum MoveCommentAfter {}
+
+    impl RuleMeta for MoveCommentAfter {
+        type Group = TestGroup;
+        const METADATA: RuleMetadata = RuleMetadata::new("0.0.0", "moveCommentAfter", "");
+    }
+
+    impl Rule for MoveCommentAfter {
+        type Query = Ast<RawLanguageRoot>;
+        type State = SyntaxToken<RawLanguage>;
+        type Signals = Option<Self::State>;
+        type Options = ();
+
+        fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+            ctx.query().syntax().first_token()
+        }
+
+        fn action(ctx: &RuleContext<Self>, token: &Self::State) -> Option<RuleAction<RawLanguage>> {
+            let comment = token
+                .leading_trivia()
+                .pieces()
+                .find(|piece| piece.is_comments())?;
+
+            Some(RuleAction {
+                category: ActionCategory::QuickFix,
+                applicability: Applicability::Always,
+                message: markup! { "Move the comment after the token" }.to_owned(),
+                mutation: ctx.trivia_mutation(
+                    token.clone(),
+                    &[],
+                    &[
+                        (TriviaPieceKind::Whitespace, " "),
+                        (comment.kind(), comment.text()),
+                    ],
+                ),
+            })
+        }
+    }
+
+    fn run_with_file_kind(file_kind: FileKind) -> Option<()> {
+        let mut builder = RawSyntaxTreeBuilder::new();
+        builder.start_node(RawLanguageKind::ROOT).finish_node();
+        let root = RawLanguageRoot::unwrap_cast(builder.finish());
+
+        let services = ServiceBag::default();
+        let options = AnalyzerOptions {
+            file_kind,
+            ..AnalyzerOptions::default()
+        };
+
+        let ctx = RuleContext::<NoTestFile>::new(FileId::zero(), &root, &root, &services, &options)
+            .unwrap();
+
+        assert_eq!(ctx.file_kind(), file_kind);
+        NoTestFile::run(&ctx)
+    }
+
+    #[test]
+    fn text_of_ranges() {
+        let mut builder = RawSyntaxTreeBuilder::new();
+        builder
+            .start_node(RawLanguageKind::ROOT)
+            .start_node(RawLanguageKind::LITERAL_EXPRESSION)
+            .token(RawLanguageKind::STRING_TOKEN, "ab")
+            .finish_node()
+            .start_node(RawLanguageKind::LITERAL_EXPRESSION)
+            .token(RawLanguageKind::STRING_TOKEN, "cd")
+            .finish_node()
+            .finish_node();
+        let root = RawLanguageRoot::unwrap_cast(builder.finish());
+
+        let services = ServiceBag::default();
+        let options = AnalyzerOptions::default();
+        let ctx = RuleContext::<NoTestFile>::new(FileId::zero(), &root, &root, &services, &options)
+            .unwrap();
+
+        let cases = [
+            (0, 4, "abcd"),
+            (1, 3, "bc"),
+            (2, 4, "cd"),
+            (3, 4, "d"),
+            (2, 2, ""),
+        ];
+
+        for (start, end, expected) in cases {
+            let range = TextRange::new(TextSize::from(start), TextSize::from(end));
+            assert_eq!(ctx.text_of(range), expected, "range: {range:?}");
+        }
+    }
+
+    #[test]
+    fn trivia_mutation_of_a_rule() {
+        let mut builder = RawSyntaxTreeBuilder::new();
+        builder.start_node(RawLanguageKind::ROOT);
+        builder.token_with_trivia(
+            RawLanguageKind::STRING_TOKEN,
+            "/* a */ b",
+            &[
+                TriviaPiece::new(TriviaPieceKind::MultiLineComment, 7),
+                TriviaPiece::new(TriviaPieceKind::Whitespace, 1),
+            ],
+            &[],
+        );
+        builder.token(RawLanguageKind::SEMICOLON_TOKEN, ";");
+        builder.finish_node();
+        let root = RawLanguageRoot::unwrap_cast(builder.finish());
+
+        let services = ServiceBag::default();
+        let options = AnalyzerOptions::default();
+        let ctx =
+            RuleContext::<MoveCommentAfter>::new(FileId::zero(), &root, &root, &services, &options)
+                .unwrap();
+
+        let token = MoveCommentAfter::run(&ctx).unwrap();
+        let action = MoveCommentAfter::action(&ctx, &token).unwrap();
+
+        assert_eq!(action.mutation.commit().to_string(), "b /* a */;");
+    }
+
+    #[test]
+    fn rules_see_the_kind_of_the_file() {
+        assert_eq!(run_with_file_kind(FileKind::Test), Some(()));
+        assert_eq!(run_with_file_kind(FileKind::Source), None);
+    }
+}
@@ -12,9 +12,11 @@ mod matcher;
 mod options;
 mod query;
 mod registry;
+mod report;
 mod rule;
 mod services;
 mod signals;
+mod suppressions;
 mod syntax;
 mod visitor;
 
@@ -23,19 +25,25 @@ pub use rome_diagnostics::v2::category_concat;
 
 pub use crate::categories::{ActionCategory, RuleCategories, RuleCategory};
 pub use crate::matcher::{InspectMatcher, MatchQueryParams, QueryMatcher, RuleKey, SignalEntry};
-pub use crate::options::{AnalyzerConfiguration, AnalyzerOptions, AnalyzerRules};
+pub use crate::options::{
+    AnalyzerConfiguration, AnalyzerOptions, AnalyzerRules, FileKind, SuppressionStyle,
+};
 pub use crate::query::{Ast, QueryKey, QueryMatch, Queryable};
 pub use crate::registry::{
     LanguageRoot, MetadataRegistry, Phase, Phases, RegistryRuleMetadata, RegistryVisitor,
     RuleRegistry, RuleRegistryBuilder, RuleSuppressions,
 };
+pub use crate::report::{FixReport, FixReportEdit, FixReportEntry, FixReportFix};
 pub use crate::rule::{
     CategoryLanguage, GroupCategory, GroupLanguage, Rule, RuleAction, RuleDiagnostic, RuleGroup,
     RuleMeta, RuleMetadata,
 };
 pub use crate::services::{FromServices, MissingServicesDiagnostic, ServiceBag};
 use crate::signals::DiagnosticSignal;
-pub use crate::signals::{AnalyzerAction, AnalyzerSignal};
+pub use crate::signals::{
+    AnalyzerAction, AnalyzerActionIter, AnalyzerSignal, AppliedActions, AppliedFix,
+};
+pub use crate::suppressions::{SuppressionHook, SuppressionScope};
 pub use crate::syntax::SyntaxVisitor;
 pub use crate::visitor::{NodeVisitor, Visitor, VisitorContext, VisitorFinishContext};
 use rome_console::{markup, MarkupBuf};
@@ -108,7 +116,13 @@ where
             .push(Box::new(visitor));
     }
 
-    pub fn run(self, mut ctx: AnalyzerContext<L>) -> Option<Break> {
+    pub fn run(self, ctx: AnalyzerContext<L>) -> Option<Break> {
+        self.run_with_summary(ctx).0
+    }
+
+    /// Runs the analyzer like [Analyzer::run], additionally returning how many diagnostics
+    /// were emitted and suppressed in the file
+    pub fn run_with_summary(self, mut ctx: AnalyzerContext<L>) -> (Option<Break>, AnalyzerSummary) {
         let Self {
             phases,
             metadata,
@@ -117,8 +131,15 @@ where
             mut emit_signal,
         } = self;
 
+        let mut summary = AnalyzerSummary::default();
+
+        if ctx.options.skip_generated && ctx.options.is_generated(ctx.root.syntax()) {
+            return (None, summary);
+        }
+
         let mut line_index = 0;
         let mut line_suppressions = Vec::new();
+        let mut file_suppression = FileSuppression::default();
 
         for (index, (phase, mut visitors)) in phases.into_iter().enumerate() {
             let runner = PhaseRunner {
@@ -130,7 +151,9 @@ where
                 parse_suppression_comment,
                 line_index: &mut line_index,
                 line_suppressions: &mut line_suppressions,
+                file_suppression: &mut file_suppression,
                 emit_signal: &mut emit_signal,
+                summary: &mut summary,
                 file_id: ctx.file_id,
                 root: &ctx.root,
                 services: &ctx.services,
@@ -141,6 +164,7 @@ where
             // The first phase being run will inspect the tokens and parse the
             // suppression comments, then subsequent phases only needs to read
             // this data again since it's already cached in `line_suppressions`
+            // and `file_suppression`
             let result = if index == 0 {
                 runner.run_first_phase()
             } else {
@@ -148,7 +172,7 @@ where
             };
 
             if let ControlFlow::Break(br) = result {
-                return Some(br);
+                return (Some(br), summary);
             }
 
             // Finish all the active visitors, this is executed outside of the
@@ -162,10 +186,21 @@ where
             }
         }
 
-        None
+        (None, summary)
     }
 }
 
+/// Counts of the diagnostics processed by the analyzer for a file, returned by
+/// [Analyzer::run_with_summary]. The signals without a diagnostic, such as the
+/// actions of the assists, aren't counted
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct AnalyzerSummary {
+    /// Number of diagnostics passed to the signal handler
+    pub emitted: usize,
+    /// Number of diagnostics discarded because a suppression comment covers them
+    pub suppressed: usize,
+}
+
 /// Holds all the state required to run a single analysis phase to completion
 struct PhaseRunner<'analyzer, 'phase, L: Language, Matcher, Break> {
     /// Identifier of the phase this runner is executing
@@ -184,8 +219,12 @@ struct PhaseRunner<'analyzer, 'phase, L: Language, Matcher, Break> {
     line_index: &'phase mut usize,
     /// Track active suppression comments per-line, ordered by line index
     line_suppressions: &'phase mut Vec<LineSuppression>,
+    /// Rules suppressed in the whole file by the suppression comments at the top of the file
+    file_suppression: &'phase mut FileSuppression,
     /// Handles analyzer signals emitted by invidual rules
     emit_signal: &'phase mut SignalHandler<'analyzer, L, Break>,
+    /// Counts the emitted and suppressed diagnostics
+    summary: &'phase mut AnalyzerSummary,
     /// ID if the file being analyzed
     file_id: FileId,
     /// Root node of the file being analyzed
@@ -213,6 +252,21 @@ struct LineSuppression {
     suppressed_rules: Vec<RuleFilter<'static>>,
 }
 
+/// Rules suppressed in the whole file by `rome-ignore-all` comments
+#[derive(Debug, Default)]
+struct FileSuppression {
+    /// Set to true if a comment suppresses all the rules
+    suppress_all: bool,
+    /// List of all the rules suppressed by the comments
+    suppressed_rules: Vec<RuleFilter<'static>>,
+}
+
+impl FileSuppression {
+    fn is_suppressed(&self, rule: &RuleKey) -> bool {
+        self.suppress_all || self.suppressed_rules.iter().any(|filter| *filter == *rule)
+    }
+}
+
 impl<'a, 'phase, L, Matcher, Break> PhaseRunner<'a, 'phase, L, Matcher, Break>
 where
     L: Language,
@@ -290,6 +344,10 @@ where
     /// handling line breaks, then flush all pending query signals in the queue
     /// whose position is less then the end of the token within the file
     fn handle_token(&mut self, file_id: FileId, token: SyntaxToken<L>) -> ControlFlow<Break> {
+        // File suppressions are only read from the comments at the top of the file,
+        // before any signal could have been emitted
+        let is_file_header = token.prev_token().is_none();
+
         // Process the content of the token for comments and newline
         for piece in token.leading_trivia().pieces() {
             if matches!(
@@ -302,7 +360,7 @@ where
             }
 
             if let Some(comment) = piece.as_comments() {
-                self.handle_comment(file_id, comment.text(), piece.text_range())?;
+                self.handle_comment(file_id, comment.text(), piece.text_range(), is_file_header)?;
             }
         }
 
@@ -319,7 +377,7 @@ where
             }
 
             if let Some(comment) = piece.as_comments() {
-                self.handle_comment(file_id, comment.text(), piece.text_range())?;
+                self.handle_comment(file_id, comment.text(), piece.text_range(), false)?;
             }
         }
 
@@ -365,15 +423,25 @@ where
                     Some(&self.line_suppressions[index.ok()?])
                 });
 
-            let is_suppressed = suppression.map_or(false, |suppression| {
-                if suppression.suppress_all {
-                    return true;
+            let is_suppressed = self.file_suppression.is_suppressed(&entry.rule)
+                || suppression.map_or(false, |suppression| {
+                    if suppression.suppress_all {
+                        return true;
+                    }
+                    suppression
+                        .suppressed_rules
+                        .iter()
+                        .any(|filter| *filter == entry.rule)
+                });
+
+            // Only the signals with a diagnostic are counted, not the actions of the assists
+            if entry.signal.has_diagnostic() {
+                if is_suppressed {
+                    self.summary.suppressed += 1;
+                } else {
+                    self.summary.emitted += 1;
                 }
-                suppression
-                    .suppressed_rules
-                    .iter()
-                    .any(|filter| *filter == entry.rule)
-            });
+            }
 
             // Emit the signal if the rule that created it is not currently being suppressed
             if !is_suppressed {
@@ -389,17 +457,47 @@ where
     }
 
     /// Parse the text content of a comment trivia piece for suppression
-    /// comments, and create line suppression entries accordingly
+    /// comments, and create line suppression entries accordingly.
+    ///
+    /// The file suppressions are only applied if the comment is in the header of the file,
+    /// a warning is emitted for the file suppressions found anywhere else
     fn handle_comment(
         &mut self,
         file_id: FileId,
         text: &str,
         range: TextRange,
+        is_file_header: bool,
     ) -> ControlFlow<Break> {
         let mut suppress_all = false;
         let mut suppressions = Vec::new();
+        let mut has_misplaced_file_suppression = false;
+
+        for (scope, rule) in (self.parse_suppression_comment)(text) {
+            if scope == SuppressionScope::File && !is_file_header {
+                // Emit a single warning for all the rules of the comment
+                if !has_misplaced_file_suppression {
+                    has_misplaced_file_suppression = true;
+
+                    let signal = DiagnosticSignal::new(move || {
+                        let diag = SuppressionDiagnostic::new(
+                            file_id,
+                            category!("suppressions/misplacedFileSuppression"),
+                            range,
+                            markup! {
+                                "File suppression comments are only applied at the start of the file"
+                            },
+                        );
+
+                        AnalyzerDiagnostic::from_error(diag.into())
+                    });
+
+                    self.summary.emitted += 1;
+                    (self.emit_signal)(&signal)?;
+                }
+
+                continue;
+            }
 
-        for rule in (self.parse_suppression_comment)(text) {
             if let Some(rule) = rule {
                 let group_rule = rule.find('/').map(|index| {
                     let (start, end) = rule.split_at(index);
@@ -414,7 +512,12 @@ where
                 };
 
                 if let Some(key) = key {
-                    suppressions.push(key);
+                    match scope {
+                        SuppressionScope::Line => suppressions.push(key),
+                        SuppressionScope::File => {
+                            self.file_suppression.suppressed_rules.push(key);
+                        }
+                    }
                 } else {
                     // Emit a warning for the unknown rule
                     let signal = DiagnosticSignal::new(move || {
@@ -441,8 +544,11 @@ where
                         AnalyzerDiagnostic::from_error(diag.into())
                     });
 
+                    self.summary.emitted += 1;
                     (self.emit_signal)(&signal)?;
                 }
+            } else if scope == SuppressionScope::File {
+                self.file_suppression.suppress_all = true;
             } else {
                 suppressions.clear();
                 suppress_all = true;
@@ -522,16 +628,17 @@ where
 /// Signature for a suppression comment parser function
 ///
 /// This function receives the text content of a comment and returns a list of
-/// lint suppressions as an optional lint rule (if the lint rule is `None` the
-/// comment is interpreted as suppressing all lints)
+/// lint suppressions as the scope of the suppression and an optional lint rule
+/// (if the lint rule is `None` the comment is interpreted as suppressing all lints)
 ///
 /// # Examples
 ///
 /// - `// rome-ignore format` -> `vec![]`
-/// - `// rome-ignore lint` -> `vec![None]`
-/// - `// rome-ignore lint(correctness/useWhile)` -> `vec![Some("correctness/useWhile")]`
-/// - `// rome-ignore lint(correctness/useWhile) lint(nursery/noUnreachable)` -> `vec![Some("correctness/useWhile"), Some("nursery/noUnreachable")]`
-type SuppressionParser = fn(&str) -> Vec<Option<&str>>;
+/// - `// rome-ignore lint` -> `vec![(Line, None)]`
+/// - `// rome-ignore lint(correctness/useWhile)` -> `vec![(Line, Some("correctness/useWhile"))]`
+/// - `// rome-ignore lint(correctness/useWhile) lint(nursery/noUnreachable)` -> `vec![(Line, Some("correctness/useWhile")), (Line, Some("nursery/noUnreachable"))]`
+/// - `// rome-ignore-all lint(correctness/useWhile)` -> `vec![(File, Some("correctness/useWhile"))]`
+type SuppressionParser = fn(&str) -> Vec<(SuppressionScope, Option<&str>)>;
 
 type SignalHandler<'a, L, Break> = &'a mut dyn FnMut(&dyn AnalyzerSignal<L>) -> ControlFlow<Break>;
 
@@ -721,7 +828,7 @@ impl Diagnostic for AnalyzerDiagnostic {
                     )?;
                     if let Some(location) = Location::builder()
                         .span(&detail.range)
-                        .resource(file_id)
+                        .resource(detail.file_id.as_ref().unwrap_or(file_id))
                         .build()
                     {
                         visitor.record_frame(location)?;
@@ -163,7 +163,7 @@ mod tests {
     use crate::{
         signals::DiagnosticSignal, Analyzer, AnalyzerContext, AnalyzerDiagnostic, AnalyzerOptions,
         AnalyzerSignal, ControlFlow, MetadataRegistry, Never, Phases, QueryMatch, QueryMatcher,
-        RuleKey, ServiceBag, SignalEntry, SyntaxVisitor,
+        RuleKey, ServiceBag, SignalEntry, SuppressionScope, SyntaxVisitor,
     };
 
     use super::MatchQueryParams;
@@ -306,11 +306,11 @@ mod tests {
             ControlFlow::Continue(())
         };
 
-        fn parse_suppression_comment(comment: &str) -> Vec<Option<&str>> {
+        fn parse_suppression_comment(comment: &str) -> Vec<(SuppressionScope, Option<&str>)> {
             comment
                 .trim_start_matches("//")
                 .split(' ')
-                .map(Some)
+                .map(|rule| (SuppressionScope::Line, Some(rule)))
                 .collect()
         }
 
@@ -359,4 +359,90 @@ mod tests {
             ]
         );
     }
+
+    fn run_generated(options: &AnalyzerOptions) -> usize {
+        let root = {
+            let mut builder = RawSyntaxTreeBuilder::new();
+
+            builder.start_node(RawLanguageKind::ROOT);
+            builder.start_node(RawLanguageKind::EXPRESSION_LIST);
+
+            builder.start_node(RawLanguageKind::LITERAL_EXPRESSION);
+            builder.token_with_trivia(
+                RawLanguageKind::STRING_TOKEN,
+                "// @generated\n\"warn_here\"",
+                &[
+                    TriviaPiece::new(TriviaPieceKind::SingleLineComment, 13),
+                    TriviaPiece::new(TriviaPieceKind::Newline, 1),
+                ],
+                &[],
+            );
+            builder.finish_node();
+
+            builder.finish_node();
+            builder.finish_node();
+
+            RawLanguageRoot::unwrap_cast(builder.finish())
+        };
+
+        let mut signals = 0;
+        let mut emit_signal = |_: &dyn AnalyzerSignal<RawLanguage>| -> ControlFlow<Never> {
+            signals += 1;
+            ControlFlow::Continue(())
+        };
+
+        fn parse_suppression_comment(_: &str) -> Vec<(SuppressionScope, Option<&str>)> {
+            Vec::new()
+        }
+
+        let mut metadata = MetadataRegistry::default();
+        metadata.insert_rule("group", "rule");
+
+        let mut analyzer = Analyzer::new(
+            &metadata,
+            SuppressionMatcher,
+            parse_suppression_comment,
+            &mut emit_signal,
+        );
+
+        analyzer.add_visitor(Phases::Syntax, SyntaxVisitor::default());
+
+        let ctx: AnalyzerContext<RawLanguage> = AnalyzerContext {
+            file_id: FileId::zero(),
+            root,
+            range: None,
+            services: ServiceBag::default(),
+            options,
+        };
+
+        let result: Option<Never> = analyzer.run(ctx);
+        assert!(result.is_none());
+
+        signals
+    }
+
+    #[test]
+    fn skip_generated() {
+        assert_eq!(run_generated(&AnalyzerOptions::default()), 1);
+
+        let options = AnalyzerOptions {
+            skip_generated: true,
+            ..AnalyzerOptions::default()
+        };
+        assert_eq!(run_generated(&options), 0);
+
+        let options = AnalyzerOptions {
+            skip_generated: true,
+            generated_marker: String::from("@autogenerated"),
+            ..AnalyzerOptions::default()
+        };
+        assert_eq!(run_generated(&options), 1);
+
+        let options = AnalyzerOptions {
+            skip_generated: true,
+            generated_marker: String::new(),
+            ..AnalyzerOptions::default()
+        };
+        assert_eq!(run_generated(&options), 1);
+    }
 }
@@ -1,5 +1,6 @@
 use crate::{RuleKey, TextRange, TextSize};
 use rome_diagnostics::v2::{Diagnostic, LineIndexBuf, Resource, SourceCode};
+use rome_rowan::{Language, SyntaxNode};
 use serde::Deserialize;
 use serde_json::Error;
 use serde_json::Value;
@@ -50,10 +51,96 @@ pub struct AnalyzerConfiguration {
 }
 
 /// A set of information useful to the analyzer infrastructure
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct AnalyzerOptions {
     /// A data structured derived from the [`rome.json`] file
     pub configuration: AnalyzerConfiguration,
+
+    /// When `true`, the analyzer doesn't emit any signal for files whose first
+    /// leading comment contains the [AnalyzerOptions::generated_marker]
+    pub skip_generated: bool,
+
+    /// The text identifying a generated file, `@generated` by default. An empty
+    /// marker never matches
+    pub generated_marker: String,
+
+    /// When `true`, suppression actions insert a `// TODO(<rule>)` comment
+    /// containing the message of the diagnostic above the `rome-ignore` comment
+    pub suppression_with_todo: bool,
+
+    /// The form of the comments inserted by suppression actions
+    pub suppression_style: SuppressionStyle,
+
+    /// When `true`, the suppression actions are followed by an action suppressing
+    /// the rule in the whole file with a `// rome-ignore-all` comment
+    pub file_suppression: bool,
+
+    /// The kind of the file being analyzed, classified by the caller from the path of
+    /// the file, for example by matching it against the test file globs of the workspace
+    pub file_kind: FileKind,
+}
+
+/// The kind of a file, for rules whose checks depend on the role of the file in the project
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum FileKind {
+    /// A source file of the project
+    #[default]
+    Source,
+    /// A file containing tests, where rules may be relaxed, for example to allow `console.log`
+    Test,
+}
+
+impl FileKind {
+    pub const fn is_test(&self) -> bool {
+        matches!(self, FileKind::Test)
+    }
+}
+
+/// The form of the comments inserted by suppression actions.
+///
+/// The comment is inserted on its own line, above the line where the diagnostic starts.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum SuppressionStyle {
+    /// `// rome-ignore lint(<group>/<rule>): <explanation>`, the form shared with the
+    /// formatter suppression comments
+    #[default]
+    Node,
+    /// `// rome-ignore-next-line lint(<group>/<rule>): <explanation>`, for users used to
+    /// suppression comments that apply to the next line
+    NextLine,
+}
+
+impl Default for AnalyzerOptions {
+    fn default() -> Self {
+        Self {
+            configuration: AnalyzerConfiguration::default(),
+            skip_generated: false,
+            generated_marker: String::from("@generated"),
+            suppression_with_todo: false,
+            suppression_style: SuppressionStyle::default(),
+            file_suppression: false,
+            file_kind: FileKind::default(),
+        }
+    }
+}
+
+impl AnalyzerOptions {
+    /// Returns `true` if the first leading comment of `root` contains the
+    /// [AnalyzerOptions::generated_marker], which can't be empty
+    pub fn is_generated<L: Language>(&self, root: &SyntaxNode<L>) -> bool {
+        if self.generated_marker.is_empty() {
+            return false;
+        }
+
+        let first_comment = root
+            .first_leading_trivia()
+            .and_then(|trivia| trivia.pieces().find_map(|piece| piece.as_comments()));
+
+        match first_comment {
+            Some(comment) => comment.text().contains(self.generated_marker.as_str()),
+            None => false,
+        }
+    }
 }
 
 #[derive(Debug, Diagnostic)]
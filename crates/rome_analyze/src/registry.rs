@@ -345,12 +345,16 @@ impl<L: Language + Default> RegistryRule<L> {
             // if the query doesn't match
             let query_result =
                 <R::Query as Queryable>::unwrap_match(params.services, &params.query);
-            let ctx =
-                match RuleContext::new(&query_result, params.root, params.services, params.options)
-                {
-                    Ok(ctx) => ctx,
-                    Err(error) => return Err(error),
-                };
+            let ctx = match RuleContext::new(
+                params.file_id,
+                &query_result,
+                params.root,
+                params.services,
+                params.options,
+            ) {
+                Ok(ctx) => ctx,
+                Err(error) => return Err(error),
+            };
 
             for result in R::run(&ctx) {
                 let text_range =
@@ -362,6 +366,7 @@ impl<L: Language + Default> RegistryRule<L> {
                     params.file_id,
                     params.root,
                     query_result.clone(),
+                    params.query.text_range(),
                     result,
                     params.services,
                     params.options.clone(),
@@ -0,0 +1,278 @@
+use crate::{AnalyzerActionIter, AnalyzerDiagnostic};
+use rome_console::fmt::{self, Formatter, Termcolor};
+use rome_console::MarkupBuf;
+use rome_diagnostics::termcolor::NoColor;
+use rome_diagnostics::v2::{Diagnostic, PrintDescription};
+use rome_diagnostics::Applicability;
+use rome_rowan::{Language, TextRange, TextSize};
+use rome_text_edit::{CompressedOp, DiffOp, TextEdit};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// Machine readable report of the diagnostics emitted by the analyzer and of
+/// the fixes proposed by their actions, for external tools that apply the
+/// fixes without going through the language server.
+///
+/// The report serializes to a JSON array with an entry per diagnostic:
+///
+/// ```json
+/// [
+///   {
+///     "rule": "lint/correctness/noDoubleEquals",
+///     "range": [4, 6],
+///     "message": "Use === instead of ==",
+///     "fixes": [
+///       {
+///         "message": "Use ===",
+///         "applicability": "MaybeIncorrect",
+///         "edits": [{ "range": [4, 6], "text": "===" }]
+///       }
+///     ]
+///   }
+/// ]
+/// ```
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct FixReport {
+    entries: Vec<FixReportEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde", derive(schemars::JsonSchema))]
+pub struct FixReportEntry {
+    /// Category of the diagnostic, for example `lint/correctness/noDoubleEquals`
+    pub rule: Option<Cow<'static, str>>,
+    /// Range of the diagnostic in the original document
+    pub range: Option<TextRange>,
+    pub message: String,
+    pub fixes: Vec<FixReportFix>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde", derive(schemars::JsonSchema))]
+pub struct FixReportFix {
+    pub message: String,
+    pub applicability: Applicability,
+    /// Replacements to apply to the original document, sorted by position.
+    /// Omitted if the edits of the fix couldn't be computed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edits: Option<Vec<FixReportEdit>>,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde", derive(schemars::JsonSchema))]
+pub struct FixReportEdit {
+    /// Range of the original document replaced by `text`
+    pub range: TextRange,
+    pub text: String,
+}
+
+impl FixReport {
+    /// Adds an entry for `diagnostic` with a fix for each of the `actions`
+    /// emitted along with it
+    pub fn add<L: Language>(
+        &mut self,
+        diagnostic: &AnalyzerDiagnostic,
+        actions: AnalyzerActionIter<L>,
+    ) {
+        let fixes = actions
+            .map(|action| FixReportFix {
+                message: print_markup(&action.message),
+                applicability: action.applicability,
+                edits: action.mutation.as_text_edits().map(|(_, text_edit)| {
+                    replacements(&action.mutation.root().to_string(), &text_edit)
+                }),
+            })
+            .collect();
+
+        self.entries.push(FixReportEntry {
+            rule: diagnostic
+                .category()
+                .map(|category| Cow::Borrowed(category.name())),
+            range: diagnostic.location().and_then(|location| location.span),
+            message: PrintDescription(diagnostic).to_string(),
+            fixes,
+        });
+    }
+
+    pub fn entries(&self) -> &[FixReportEntry] {
+        &self.entries
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Converts the operations of `text_edit` into replacements of ranges of `old`,
+/// the text the edit was computed from
+fn replacements(old: &str, text_edit: &TextEdit) -> Vec<FixReportEdit> {
+    let mut result: Vec<FixReportEdit> = Vec::new();
+    let mut offset = TextSize::from(0);
+
+    for op in text_edit.iter() {
+        match op {
+            CompressedOp::DiffOp(DiffOp::Equal { range }) => {
+                offset += range.len();
+            }
+            CompressedOp::DiffOp(DiffOp::Insert { range }) => {
+                let text = text_edit.get_text(*range).to_string();
+
+                // Merge with a previous delete operation if possible
+                let last_edit = result
+                    .last_mut()
+                    .filter(|edit| edit.range.end() == offset && edit.text.is_empty());
+
+                if let Some(last_edit) = last_edit {
+                    last_edit.text = text;
+                } else {
+                    result.push(FixReportEdit {
+                        range: TextRange::empty(offset),
+                        text,
+                    });
+                }
+            }
+            CompressedOp::DiffOp(DiffOp::Delete { range }) => {
+                result.push(FixReportEdit {
+                    range: TextRange::at(offset, range.len()),
+                    text: String::new(),
+                });
+                offset += range.len();
+            }
+            CompressedOp::EqualLines { line_count } => {
+                let skipped: usize = old[usize::from(offset)..]
+                    .split_inclusive('\n')
+                    .take(line_count.get() as usize + 1)
+                    .map(str::len)
+                    .sum();
+
+                // SAFETY: The skipped lines are a slice of `old`, whose length fits in a `TextSize`
+                offset += TextSize::try_from(skipped).unwrap();
+            }
+        }
+    }
+
+    result
+}
+
+fn print_markup(markup: &MarkupBuf) -> String {
+    let mut message = Termcolor(NoColor::new(Vec::new()));
+    fmt::Display::fmt(markup, &mut Formatter::new(&mut message))
+        // SAFETY: Writing to a memory buffer should never fail
+        .unwrap();
+
+    // SAFETY: Printing uncolored markup never generates non UTF-8 byte sequences
+    String::from_utf8(message.0.into_inner()).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use rome_console::markup;
+    use rome_diagnostics::{file::FileId, v2::category, Applicability};
+    use rome_rowan::{
+        raw_language::{RawLanguage, RawLanguageKind, RawLanguageRoot, RawSyntaxTreeBuilder},
+        AstNode, BatchMutationExt, Direction, TextRange, TextSize,
+    };
+
+    use super::{FixReport, FixReportEdit};
+    use crate::{
+        ActionCategory, AnalyzerAction, AnalyzerActionIter, AnalyzerDiagnostic, RuleDiagnostic,
+    };
+
+    /// Creates a root with the source text `a b`, the edits being computed per word
+    fn root() -> RawLanguageRoot {
+        let mut builder = RawSyntaxTreeBuilder::new();
+        builder
+            .start_node(RawLanguageKind::ROOT)
+            .start_node(RawLanguageKind::LITERAL_EXPRESSION)
+            .token(RawLanguageKind::STRING_TOKEN, "a")
+            .finish_node()
+            .start_node(RawLanguageKind::LITERAL_EXPRESSION)
+            .token(RawLanguageKind::STRING_TOKEN, " b")
+            .finish_node()
+            .finish_node();
+
+        RawLanguageRoot::unwrap_cast(builder.finish())
+    }
+
+    /// Creates an action removing the token with the given text, or an empty action
+    fn action(
+        root: &RawLanguageRoot,
+        remove: Option<&str>,
+        applicability: Applicability,
+    ) -> AnalyzerAction<RawLanguage> {
+        let mut mutation = root.clone().begin();
+        if let Some(text) = remove {
+            let token = root
+                .syntax()
+                .descendants_tokens(Direction::Next)
+                .find(|token| token.text() == text)
+                .unwrap();
+            mutation.remove_token(token);
+        }
+
+        AnalyzerAction {
+            group_name: "group",
+            rule_name: "rule",
+            file_id: FileId::zero(),
+            category: ActionCategory::QuickFix,
+            applicability,
+            message: markup! { "Remove "<Emphasis>"the token"</Emphasis> }.to_owned(),
+            mutation,
+            span: None,
+        }
+    }
+
+    fn diagnostic() -> AnalyzerDiagnostic {
+        let range = TextRange::new(TextSize::from(1), TextSize::from(2));
+        AnalyzerDiagnostic::from_rule_diagnostic(
+            FileId::zero(),
+            RuleDiagnostic::new(category!("lint/correctness/noDebugger"), range, "message"),
+        )
+    }
+
+    #[test]
+    fn reports_edits() {
+        let root = root();
+
+        let mut report = FixReport::default();
+        report.add(
+            &diagnostic(),
+            AnalyzerActionIter::new(vec![action(&root, Some("a"), Applicability::Always)]),
+        );
+
+        let entry = &report.entries()[0];
+        assert_eq!(entry.rule.as_deref(), Some("lint/correctness/noDebugger"));
+        assert_eq!(entry.message, "message");
+        assert_eq!(entry.fixes[0].message, "Remove the token");
+        assert_eq!(
+            entry.fixes[0].edits.as_deref(),
+            Some(
+                &[FixReportEdit {
+                    range: TextRange::new(TextSize::from(0), TextSize::from(1)),
+                    text: String::new(),
+                }][..]
+            )
+        );
+    }
+
+    #[test]
+    fn serialize() {
+        let root = root();
+
+        let mut report = FixReport::default();
+        report.add(
+            &diagnostic(),
+            AnalyzerActionIter::new(vec![
+                action(&root, Some("a"), Applicability::MaybeIncorrect),
+                action(&root, None, Applicability::Always),
+            ]),
+        );
+
+        assert_eq!(
+            serde_json::to_string(&report).unwrap(),
+            r#"[{"rule":"lint/correctness/noDebugger","range":[1,2],"message":"message","fixes":[{"message":"Remove the token","applicability":"MaybeIncorrect","edits":[{"range":[0,1],"text":""}]},{"message":"Remove the token","applicability":"Always"}]}]"#
+        );
+    }
+}
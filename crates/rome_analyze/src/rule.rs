@@ -4,7 +4,7 @@ use crate::registry::{RegistryVisitor, RuleLanguage, RuleSuppressions};
 use crate::{AnalyzerDiagnostic, Phase, Phases, Queryable};
 use rome_console::fmt::Display;
 use rome_console::{markup, MarkupBuf};
-use rome_diagnostics::file::FileId;
+use rome_diagnostics::file::{FileId, FileSpan};
 use rome_diagnostics::v2::advice::CodeSuggestionAdvice;
 use rome_diagnostics::v2::location::AsSpan;
 use rome_diagnostics::v2::{
@@ -361,7 +361,11 @@ impl Advices for RuleAdvice {
                 detail.log_category,
                 &markup! { {detail.message} }.to_owned(),
             )?;
-            if let Some(location) = Location::builder().span(&detail.range).build() {
+            if let Some(location) = Location::builder()
+                .span(&detail.range)
+                .resource(&detail.file_id)
+                .build()
+            {
                 visitor.record_frame(location)?;
             }
         }
@@ -384,6 +388,8 @@ pub struct Detail {
     pub log_category: LogCategory,
     pub message: MarkupBuf,
     pub range: Option<TextRange>,
+    /// The file the range belongs to, if it's not the file of the diagnostic
+    pub file_id: Option<FileId>,
 }
 
 impl RuleDiagnostic {
@@ -432,6 +438,20 @@ impl RuleDiagnostic {
             log_category: LogCategory::Info,
             message: markup!({ msg }).to_owned(),
             range: span.as_span(),
+            file_id: None,
+        });
+        self
+    }
+
+    /// Attaches a label pointing to a location in another file to this [`RuleDiagnostic`].
+    ///
+    /// Useful for project-wide rules to point at all the locations involved in a diagnostic.
+    pub fn file_label(mut self, span: FileSpan, msg: impl Display) -> Self {
+        self.rule_advice.details.push(Detail {
+            log_category: LogCategory::Info,
+            message: markup!({ msg }).to_owned(),
+            range: Some(span.range),
+            file_id: Some(span.file),
         });
         self
     }
@@ -463,6 +483,12 @@ impl RuleDiagnostic {
         self.span
     }
 
+    /// Returns the text of the message of this diagnostic, stripped of its markup
+    pub(crate) fn message_text(&self) -> String {
+        let message = markup!({ self.message }).to_owned();
+        message.0.into_iter().map(|node| node.content).collect()
+    }
+
     /// Convert this [`RuleDiagnostic`] into an instance of [`AnalyzerDiagnostic`] by
     /// injecting the name of the rule that emitted it and the ID of the file
     /// the rule was being run on
@@ -482,3 +508,50 @@ pub struct RuleAction<L: Language> {
     pub message: MarkupBuf,
     pub mutation: BatchMutation<L>,
 }
+
+#[cfg(test)]
+mod tests {
+    use rome_diagnostics::file::{FileId, FileSpan};
+    use rome_diagnostics::v2::{category, Diagnostic, FilePath, Location, Resource, Visit};
+    use rome_rowan::{TextRange, TextSize};
+
+    use super::RuleDiagnostic;
+    use crate::AnalyzerDiagnostic;
+
+    #[derive(Default)]
+    struct FrameResources(Vec<Option<FileId>>);
+
+    impl Visit for FrameResources {
+        fn record_frame(&mut self, location: Location<'_>) -> std::io::Result<()> {
+            let file_id = match location.resource {
+                Resource::File(FilePath::FileId(file_id)) => Some(file_id),
+                _ => None,
+            };
+
+            self.0.push(file_id);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn file_labels_point_to_other_files() {
+        let range = TextRange::new(TextSize::from(0), TextSize::from(1));
+
+        let rule_diagnostic = RuleDiagnostic::new(category!("args/fileNotFound"), range, "message")
+            .detail(range, "in the same file")
+            .file_label(
+                FileSpan {
+                    file: FileId::from(1),
+                    range,
+                },
+                "in another file",
+            );
+
+        let diagnostic = AnalyzerDiagnostic::from_rule_diagnostic(FileId::zero(), rule_diagnostic);
+
+        let mut resources = FrameResources::default();
+        diagnostic.advices(&mut resources).unwrap();
+
+        assert_eq!(resources.0, [Some(FileId::zero()), Some(FileId::from(1))]);
+    }
+}
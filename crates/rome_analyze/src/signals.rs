@@ -3,19 +3,32 @@ use crate::{
     context::RuleContext,
     registry::{RuleLanguage, RuleRoot},
     rule::Rule,
+    suppressions::{file_suppression_mutation, suppression_mutation},
     AnalyzerDiagnostic, AnalyzerOptions, Queryable, RuleGroup, ServiceBag,
 };
-use rome_console::MarkupBuf;
+use rome_console::{markup, MarkupBuf};
 use rome_diagnostics::file::FileSpan;
 use rome_diagnostics::v2::advice::CodeSuggestionAdvice;
 use rome_diagnostics::{file::FileId, Applicability, CodeSuggestion};
-use rome_rowan::{BatchMutation, Language};
+use rome_rowan::{AstNode, BatchMutation, Language, SyntaxNode, TextRange, TextSize};
+use std::vec::IntoIter;
 
 /// Event raised by the analyzer when a [Rule](crate::Rule)
 /// emits a diagnostic, a code action, or both
 pub trait AnalyzerSignal<L: Language> {
     fn diagnostic(&self) -> Option<AnalyzerDiagnostic>;
     fn action(&self) -> Option<AnalyzerAction<L>>;
+
+    /// Returns `true` if this signal emits a diagnostic. Implementations can override
+    /// it to skip the work of [AnalyzerSignal::diagnostic] not needed to know the result
+    fn has_diagnostic(&self) -> bool {
+        self.diagnostic().is_some()
+    }
+
+    /// Returns all the code actions emitted by this signal
+    fn actions(&self) -> AnalyzerActionIter<L> {
+        AnalyzerActionIter::new(self.action().into_iter().collect())
+    }
 }
 
 /// Simple implementation of [AnalyzerSignal] generating a [AnalyzerDiagnostic] from a
@@ -41,6 +54,10 @@ where
         Some((self.factory)())
     }
 
+    fn has_diagnostic(&self) -> bool {
+        true
+    }
+
     fn action(&self) -> Option<AnalyzerAction<L>> {
         None
     }
@@ -60,6 +77,39 @@ pub struct AnalyzerAction<L: Language> {
     pub applicability: Applicability,
     pub message: MarkupBuf,
     pub mutation: BatchMutation<L>,
+    /// Primary range of the document affected by this action, for example the
+    /// node matched by the rule. Editors can use it to position the action
+    /// without computing the text edits of the mutation
+    pub span: Option<TextRange>,
+}
+
+impl<L: Language> AnalyzerAction<L> {
+    /// Returns a summary of the changes made by this action once applied, for
+    /// example to log the fixes applied to a file.
+    ///
+    /// Returns `None` if the mutation of the action doesn't modify the document
+    pub fn applied_fix(&self) -> Option<AppliedFix> {
+        let ranges = self.mutation.text_ranges();
+        let range = ranges.first()?.cover(*ranges.last()?);
+
+        Some(AppliedFix {
+            rule_name: self.rule_name,
+            message: self.message.clone(),
+            range,
+        })
+    }
+}
+
+/// Summary of an [AnalyzerAction] applied to a document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppliedFix {
+    /// Name of the rule that emitted the action
+    pub rule_name: &'static str,
+    /// Message of the action describing the change
+    pub message: MarkupBuf,
+    /// Range of the document modified by the action, in the source before
+    /// the action was applied
+    pub range: TextRange,
 }
 
 impl<L> From<AnalyzerAction<L>> for CodeSuggestionAdvice<MarkupBuf>
@@ -83,6 +133,15 @@ where
 {
     fn from(action: AnalyzerAction<L>) -> Self {
         let (range, suggestion) = action.mutation.as_text_edits().unwrap_or_default();
+        let range = action.span.unwrap_or(range);
+
+        // The span is the range the action points at, falling back to the range
+        // covering all the changes. When the mutation modifies several disjoint
+        // ranges of the document, each of them also gets its own label
+        let mut labels = action.mutation.text_ranges();
+        if labels.len() < 2 {
+            labels.clear();
+        }
 
         CodeSuggestion {
             span: FileSpan {
@@ -92,16 +151,171 @@ where
             applicability: action.applicability,
             msg: action.message,
             suggestion,
-            labels: vec![],
+            labels,
+        }
+    }
+}
+
+/// Iterator over the [AnalyzerAction]s emitted by an [AnalyzerSignal]
+pub struct AnalyzerActionIter<L: Language> {
+    analyzer_actions: IntoIter<AnalyzerAction<L>>,
+}
+
+impl<L: Language> AnalyzerActionIter<L> {
+    pub fn new(actions: Vec<AnalyzerAction<L>>) -> Self {
+        Self {
+            analyzer_actions: actions.into_iter(),
+        }
+    }
+
+    /// Sorts the remaining actions by the start offset of the range modified by
+    /// their mutation. Actions that don't modify the document come last.
+    ///
+    /// The sort is stable: actions starting at the same offset keep their order
+    pub fn sorted_by_position(self) -> Self {
+        let mut actions: Vec<_> = self.analyzer_actions.collect();
+
+        actions.sort_by_cached_key(|action| match action.mutation.as_text_edits() {
+            Some((range, _)) => (false, range.start()),
+            None => (true, TextSize::default()),
+        });
+
+        Self::new(actions)
+    }
+
+    /// Sorts the remaining actions in the order of a code action menu, and
+    /// removes the duplicated actions.
+    ///
+    /// The actions are sorted by [Applicability], the safe fixes first, then by
+    /// [ActionCategory]: the quick fixes come before the refactors and the
+    /// suppressions. The sort is stable.
+    ///
+    /// An action is a duplicate of a previous action if both produce the same text
+    /// edits with the same message: two rules proposing the same edit with different
+    /// messages are both kept.
+    pub fn sorted_and_deduped(self) -> Self {
+        let mut actions: Vec<_> = self.analyzer_actions.collect();
+
+        actions.sort_by_key(|action| {
+            let applicability = match action.applicability {
+                Applicability::Always => 0,
+                Applicability::MaybeIncorrect => 1,
+            };
+
+            let category = match action.category {
+                ActionCategory::QuickFix => 0,
+                ActionCategory::Refactor => 1,
+                ActionCategory::Suppression => 2,
+                ActionCategory::FileSuppression => 3,
+            };
+
+            (applicability, category)
+        });
+
+        let mut edits = Vec::with_capacity(actions.len());
+        actions.retain(|action| {
+            let edit = (action.mutation.as_text_edits(), action.message.clone());
+
+            if edits.contains(&edit) {
+                false
+            } else {
+                edits.push(edit);
+                true
+            }
+        });
+
+        Self::new(actions)
+    }
+
+    /// Applies all the remaining actions that don't conflict with each other to `root`
+    /// in a single mutation.
+    ///
+    /// The actions are added to the mutation greedily, in order: an action conflicts with
+    /// the mutation if one of the ranges it modifies touches a range modified by an action
+    /// already added. An action inserting an element modifies the empty range at the
+    /// position of the insertion. The skipped actions can be applied to the updated tree
+    /// after they've been computed again, for example by running the analyzer once more.
+    ///
+    /// Actions with an [Applicability] less certain than `applicability` are skipped:
+    /// pass [Applicability::MaybeIncorrect] to apply them together with the safe fixes.
+    /// Actions whose mutation doesn't change the document are skipped too.
+    pub fn apply_non_conflicting(
+        self,
+        root: SyntaxNode<L>,
+        applicability: Applicability,
+    ) -> AppliedActions<L> {
+        let mut mutation = BatchMutation::new(root);
+        let mut ranges: Vec<TextRange> = Vec::new();
+        let mut applied = Vec::new();
+        let mut skipped = Vec::new();
+
+        for action in self.analyzer_actions {
+            let is_applicable = match applicability {
+                Applicability::Always => action.applicability == Applicability::Always,
+                Applicability::MaybeIncorrect => true,
+            };
+
+            let action_ranges = action.mutation.text_ranges();
+
+            let conflicts = action_ranges.iter().any(|range| {
+                ranges
+                    .iter()
+                    .any(|applied| applied.intersect(*range).is_some())
+            });
+
+            let fix = match action.applied_fix() {
+                Some(fix) if is_applicable && !conflicts => fix,
+                _ => {
+                    skipped.push(action);
+                    continue;
+                }
+            };
+
+            ranges.extend(action_ranges);
+            applied.push(fix);
+            mutation.merge(action.mutation);
+        }
+
+        AppliedActions {
+            root: mutation.commit(),
+            applied,
+            skipped,
         }
     }
 }
 
+/// The result of [AnalyzerActionIter::apply_non_conflicting]
+#[derive(Debug)]
+pub struct AppliedActions<L: Language> {
+    /// The tree after the actions have been applied
+    pub root: SyntaxNode<L>,
+    /// A summary of the applied actions, in the order they were applied
+    pub applied: Vec<AppliedFix>,
+    /// The actions that were skipped, in their original order
+    pub skipped: Vec<AnalyzerAction<L>>,
+}
+
+impl<L: Language> Iterator for AnalyzerActionIter<L> {
+    type Item = AnalyzerAction<L>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.analyzer_actions.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.analyzer_actions.size_hint()
+    }
+}
+
+impl<L: Language> ExactSizeIterator for AnalyzerActionIter<L> {}
+
 /// Analyzer-internal implementation of [AnalyzerSignal] for a specific [Rule](crate::registry::Rule)
 pub(crate) struct RuleSignal<'phase, R: Rule> {
     file_id: FileId,
     root: &'phase RuleRoot<R>,
     query_result: <<R as Rule>::Query as Queryable>::Output,
+    /// Range of the query match the rule was run on
+    query_range: TextRange,
     state: R::State,
     services: &'phase ServiceBag,
     options: AnalyzerOptions,
@@ -115,6 +329,7 @@ where
         file_id: FileId,
         root: &'phase RuleRoot<R>,
         query_result: <<R as Rule>::Query as Queryable>::Output,
+        query_range: TextRange,
         state: R::State,
         services: &'phase ServiceBag,
         options: AnalyzerOptions,
@@ -123,6 +338,7 @@ where
             file_id,
             root,
             query_result,
+            query_range,
             state,
             services,
             options,
@@ -133,17 +349,47 @@ where
 impl<'bag, R> AnalyzerSignal<RuleLanguage<R>> for RuleSignal<'bag, R>
 where
     R: Rule,
+    RuleLanguage<R>: 'static,
 {
     fn diagnostic(&self) -> Option<AnalyzerDiagnostic> {
-        let ctx =
-            RuleContext::new(&self.query_result, self.root, self.services, &self.options).ok()?;
+        let ctx = RuleContext::new(
+            self.file_id,
+            &self.query_result,
+            self.root,
+            self.services,
+            &self.options,
+        )
+        .ok()?;
 
         R::diagnostic(&ctx, &self.state).map(|diag| diag.into_analyzer_diagnostic(self.file_id))
     }
 
+    /// Builds the diagnostic of the rule without converting it into an [AnalyzerDiagnostic],
+    /// as the rules may not emit a diagnostic for every signal, whatever their category
+    fn has_diagnostic(&self) -> bool {
+        let ctx = RuleContext::new(
+            self.file_id,
+            &self.query_result,
+            self.root,
+            self.services,
+            &self.options,
+        );
+
+        match ctx {
+            Ok(ctx) => R::diagnostic(&ctx, &self.state).is_some(),
+            Err(_) => false,
+        }
+    }
+
     fn action(&self) -> Option<AnalyzerAction<RuleLanguage<R>>> {
-        let ctx =
-            RuleContext::new(&self.query_result, self.root, self.services, &self.options).ok()?;
+        let ctx = RuleContext::new(
+            self.file_id,
+            &self.query_result,
+            self.root,
+            self.services,
+            &self.options,
+        )
+        .ok()?;
 
         R::action(&ctx, &self.state).map(|action| AnalyzerAction {
             group_name: <R::Group as RuleGroup>::NAME,
@@ -153,6 +399,504 @@ where
             applicability: action.applicability,
             message: action.message,
             mutation: action.mutation,
+            span: Some(self.query_range),
         })
     }
+
+    /// Returns the action emitted by the rule, followed by an action
+    /// suppressing the diagnostic emitted by the rule if there is one.
+    ///
+    /// If [AnalyzerOptions::file_suppression] is enabled, the diagnostic can also
+    /// be suppressed by an action suppressing the rule in the whole file
+    fn actions(&self) -> AnalyzerActionIter<RuleLanguage<R>> {
+        let ctx = RuleContext::new(
+            self.file_id,
+            &self.query_result,
+            self.root,
+            self.services,
+            &self.options,
+        );
+
+        let ctx = match ctx {
+            Ok(ctx) => ctx,
+            Err(_) => return AnalyzerActionIter::new(Vec::new()),
+        };
+
+        let group_name = <R::Group as RuleGroup>::NAME;
+        let rule_name = R::METADATA.name;
+
+        let mut actions = Vec::new();
+
+        if let Some(action) = R::action(&ctx, &self.state) {
+            actions.push(AnalyzerAction {
+                group_name,
+                rule_name,
+                file_id: self.file_id,
+                category: action.category,
+                applicability: action.applicability,
+                message: action.message,
+                mutation: action.mutation,
+                span: Some(self.query_range),
+            });
+        }
+
+        let diagnostic = R::diagnostic(&ctx, &self.state);
+        let has_diagnostic = diagnostic.is_some();
+        let suppression = diagnostic.and_then(|diagnostic| {
+            let span = diagnostic.span()?;
+
+            // The message of the diagnostic is only needed when the
+            // suppression comes with a TODO comment
+            let todo = self
+                .options
+                .suppression_with_todo
+                .then(|| diagnostic.message_text());
+
+            suppression_mutation(
+                self.root.syntax(),
+                span.start(),
+                group_name,
+                rule_name,
+                self.options.suppression_style,
+                todo.as_deref(),
+                self.services.get_service(),
+            )
+        });
+
+        // The span of the suppression actions is the range of the suppression comment
+        if let Some((mutation, span)) = suppression {
+            actions.push(AnalyzerAction {
+                group_name,
+                rule_name,
+                file_id: self.file_id,
+                category: ActionCategory::Suppression,
+                applicability: Applicability::MaybeIncorrect,
+                message: markup! {
+                    "Suppress rule "<Emphasis>{group_name}"/"{rule_name}</Emphasis>
+                }
+                .to_owned(),
+                mutation,
+                span: Some(span),
+            });
+        }
+
+        // The file suppression doesn't depend on the position of the diagnostic
+        let file_suppression = (has_diagnostic && self.options.file_suppression)
+            .then(|| file_suppression_mutation(self.root.syntax(), group_name, rule_name))
+            .flatten();
+
+        if let Some((mutation, span)) = file_suppression {
+            actions.push(AnalyzerAction {
+                group_name,
+                rule_name,
+                file_id: self.file_id,
+                category: ActionCategory::FileSuppression,
+                applicability: Applicability::MaybeIncorrect,
+                message: markup! {
+                    "Suppress rule "<Emphasis>{group_name}"/"{rule_name}</Emphasis>" in this file"
+                }
+                .to_owned(),
+                mutation,
+                span: Some(span),
+            });
+        }
+
+        AnalyzerActionIter::new(actions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rome_console::markup;
+    use rome_diagnostics::{file::FileId, Applicability, CodeSuggestion};
+    use rome_rowan::{
+        raw_language::{RawLanguage, RawLanguageKind, RawLanguageRoot, RawSyntaxTreeBuilder},
+        AstNode, BatchMutationExt, Direction, TextRange, TextSize,
+    };
+
+    use super::{AnalyzerAction, AnalyzerActionIter, AppliedFix};
+    use crate::ActionCategory;
+
+    fn root() -> RawLanguageRoot {
+        let mut builder = RawSyntaxTreeBuilder::new();
+        builder
+            .start_node(RawLanguageKind::ROOT)
+            .start_node(RawLanguageKind::LITERAL_EXPRESSION)
+            .token(RawLanguageKind::STRING_TOKEN, "a")
+            .finish_node()
+            .start_node(RawLanguageKind::LITERAL_EXPRESSION)
+            .token(RawLanguageKind::STRING_TOKEN, "b")
+            .finish_node()
+            .finish_node();
+
+        RawLanguageRoot::unwrap_cast(builder.finish())
+    }
+
+    /// Creates a root with a literal expression for each name
+    fn literals(names: &[&str]) -> RawLanguageRoot {
+        let mut builder = RawSyntaxTreeBuilder::new();
+        builder.start_node(RawLanguageKind::ROOT);
+
+        for name in names {
+            builder
+                .start_node(RawLanguageKind::LITERAL_EXPRESSION)
+                .token(RawLanguageKind::STRING_TOKEN, name)
+                .finish_node();
+        }
+
+        builder.finish_node();
+        RawLanguageRoot::unwrap_cast(builder.finish())
+    }
+
+    /// Creates an action removing the token with the given text, or an empty action
+    fn action(
+        root: &RawLanguageRoot,
+        rule_name: &'static str,
+        remove: Option<&str>,
+    ) -> AnalyzerAction<RawLanguage> {
+        let mut mutation = root.clone().begin();
+        if let Some(text) = remove {
+            let token = root
+                .syntax()
+                .descendants_tokens(Direction::Next)
+                .find(|token| token.text() == text)
+                .unwrap();
+            mutation.remove_token(token);
+        }
+
+        AnalyzerAction {
+            group_name: "group",
+            rule_name,
+            file_id: FileId::zero(),
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::Always,
+            message: markup! { "message" }.to_owned(),
+            mutation,
+            span: None,
+        }
+    }
+
+    #[test]
+    fn sorted_by_position() {
+        let root = root();
+
+        let actions = AnalyzerActionIter::new(vec![
+            action(&root, "empty", None),
+            action(&root, "b1", Some("b")),
+            action(&root, "a", Some("a")),
+            action(&root, "b2", Some("b")),
+        ]);
+
+        let names: Vec<_> = actions
+            .sorted_by_position()
+            .map(|action| action.rule_name)
+            .collect();
+
+        assert_eq!(names, ["a", "b1", "b2", "empty"]);
+    }
+
+    #[test]
+    fn sorted_and_deduped() {
+        let root = root();
+
+        // Creates an action with its own message, so it isn't a duplicate of an action with the same edit
+        let with = |mut action: AnalyzerAction<RawLanguage>, category, applicability| {
+            action.category = category;
+            action.applicability = applicability;
+            action.message = markup! { {action.rule_name} }.to_owned();
+            action
+        };
+
+        let mut other_message = action(&root, "other_message", Some("a"));
+        other_message.message = markup! { "other message" }.to_owned();
+
+        let actions = AnalyzerActionIter::new(vec![
+            with(
+                action(&root, "suppression", Some("a")),
+                ActionCategory::Suppression,
+                Applicability::MaybeIncorrect,
+            ),
+            with(
+                action(&root, "maybe_incorrect", Some("b")),
+                ActionCategory::QuickFix,
+                Applicability::MaybeIncorrect,
+            ),
+            with(
+                action(&root, "refactor", Some("b")),
+                ActionCategory::Refactor,
+                Applicability::Always,
+            ),
+            action(&root, "quick_fix", Some("a")),
+            action(&root, "duplicate", Some("a")),
+            other_message,
+            with(
+                action(&root, "file_suppression", Some("b")),
+                ActionCategory::FileSuppression,
+                Applicability::MaybeIncorrect,
+            ),
+        ]);
+
+        let names: Vec<_> = actions
+            .sorted_and_deduped()
+            .map(|action| action.rule_name)
+            .collect();
+
+        assert_eq!(
+            names,
+            [
+                "quick_fix",
+                "other_message",
+                "refactor",
+                "maybe_incorrect",
+                "suppression",
+                "file_suppression"
+            ]
+        );
+    }
+
+    #[test]
+    fn code_suggestion_labels() {
+        let root = root();
+
+        let suggestion = CodeSuggestion::from(action(&root, "a", Some("a")));
+        assert!(suggestion.labels.is_empty());
+
+        let mut builder = RawSyntaxTreeBuilder::new();
+        builder
+            .start_node(RawLanguageKind::ROOT)
+            .token(RawLanguageKind::STRING_TOKEN, "a")
+            .token(RawLanguageKind::STRING_TOKEN, "b")
+            .token(RawLanguageKind::STRING_TOKEN, "c")
+            .finish_node();
+        let root = RawLanguageRoot::unwrap_cast(builder.finish());
+
+        let mut action = action(&root, "a", Some("a"));
+        let c = root
+            .syntax()
+            .descendants_tokens(Direction::Next)
+            .find(|token| token.text() == "c")
+            .unwrap();
+        action.mutation.remove_token(c);
+
+        let suggestion = CodeSuggestion::from(action);
+        assert_eq!(
+            suggestion.span.range,
+            TextRange::new(TextSize::from(0), TextSize::from(3))
+        );
+        assert_eq!(
+            suggestion.labels,
+            [
+                TextRange::new(TextSize::from(0), TextSize::from(1)),
+                TextRange::new(TextSize::from(2), TextSize::from(3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn code_suggestion_labels_merge_adjacent_ranges() {
+        let mut builder = RawSyntaxTreeBuilder::new();
+        builder.start_node(RawLanguageKind::ROOT);
+        for text in ["a", "b", "c", "d", "e", "f"] {
+            builder.token(RawLanguageKind::STRING_TOKEN, text);
+        }
+        builder.finish_node();
+        let root = RawLanguageRoot::unwrap_cast(builder.finish());
+
+        // Removing the adjacent `a` and `b` modifies a single range
+        let mut action = action(&root, "a", Some("a"));
+        also_remove(&root, &mut action, "b");
+        also_remove(&root, &mut action, "d");
+        also_remove(&root, &mut action, "f");
+
+        let suggestion = CodeSuggestion::from(action);
+        assert_eq!(
+            suggestion.span.range,
+            TextRange::new(TextSize::from(0), TextSize::from(6))
+        );
+        assert_eq!(
+            suggestion.labels,
+            [
+                TextRange::new(TextSize::from(0), TextSize::from(2)),
+                TextRange::new(TextSize::from(3), TextSize::from(4)),
+                TextRange::new(TextSize::from(5), TextSize::from(6)),
+            ]
+        );
+    }
+
+    #[test]
+    fn applied_fix() {
+        let root = root();
+
+        assert_eq!(
+            action(&root, "b", Some("b")).applied_fix(),
+            Some(AppliedFix {
+                rule_name: "b",
+                message: markup! { "message" }.to_owned(),
+                range: TextRange::new(TextSize::from(1), TextSize::from(2)),
+            })
+        );
+
+        assert_eq!(action(&root, "empty", None).applied_fix(), None);
+    }
+
+    /// Removes the token with the given text in the mutation of `action`
+    fn also_remove(root: &RawLanguageRoot, action: &mut AnalyzerAction<RawLanguage>, text: &str) {
+        let token = root
+            .syntax()
+            .descendants_tokens(Direction::Next)
+            .find(|token| token.text() == text)
+            .unwrap();
+        action.mutation.remove_token(token);
+    }
+
+    #[test]
+    fn apply_non_conflicting_disjoint() {
+        let root = literals(&["a", "b", "c", "d"]);
+
+        let actions = AnalyzerActionIter::new(vec![
+            action(&root, "a", Some("a")),
+            action(&root, "c", Some("c")),
+        ]);
+
+        let result = actions.apply_non_conflicting(root.syntax().clone(), Applicability::Always);
+
+        assert_eq!(result.root.to_string(), "bd");
+        assert!(result.skipped.is_empty());
+
+        let applied: Vec<_> = result.applied.iter().map(|fix| fix.range).collect();
+        assert_eq!(
+            applied,
+            [
+                TextRange::new(TextSize::from(0), TextSize::from(1)),
+                TextRange::new(TextSize::from(2), TextSize::from(3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn apply_non_conflicting_insertion() {
+        let mut builder = RawSyntaxTreeBuilder::new();
+        builder.start_node(RawLanguageKind::ROOT);
+        for text in ["a", "b", "", "c"] {
+            builder.start_node(RawLanguageKind::LITERAL_EXPRESSION);
+            if !text.is_empty() {
+                builder.token(RawLanguageKind::STRING_TOKEN, text);
+            }
+            builder.finish_node();
+        }
+        builder.finish_node();
+        let root = RawLanguageRoot::unwrap_cast(builder.finish());
+
+        // Fills the empty literal, modifying the empty range `2..2`
+        let empty = root
+            .syntax()
+            .descendants()
+            .find(|node| node.text_range().is_empty())
+            .unwrap();
+        let literal =
+            RawSyntaxTreeBuilder::wrap_with_node(RawLanguageKind::LITERAL_EXPRESSION, |builder| {
+                builder.token(RawLanguageKind::STRING_TOKEN, "x");
+            });
+        let mut insert = action(&root, "insert", None);
+        insert
+            .mutation
+            .replace_element_discard_trivia(empty.into(), literal.into());
+
+        let actions = AnalyzerActionIter::new(vec![
+            action(&root, "a", Some("a")),
+            insert,
+            // Touches the insertion
+            action(&root, "c", Some("c")),
+        ]);
+
+        let result = actions.apply_non_conflicting(root.syntax().clone(), Applicability::Always);
+
+        assert_eq!(result.root.to_string(), "bxc");
+
+        let applied: Vec<_> = result.applied.iter().map(|fix| fix.rule_name).collect();
+        assert_eq!(applied, ["a", "insert"]);
+
+        let names: Vec<_> = result
+            .skipped
+            .iter()
+            .map(|action| action.rule_name)
+            .collect();
+        assert_eq!(names, ["c"]);
+    }
+
+    #[test]
+    fn apply_non_conflicting_partially_overlapping() {
+        let root = literals(&["a", "b", "c", "d"]);
+
+        let mut first = action(&root, "first", Some("a"));
+        also_remove(&root, &mut first, "c");
+
+        // Doesn't change the same tokens, but the ranges it modifies touch the ranges of `first`
+        let mut second = action(&root, "second", Some("b"));
+        also_remove(&root, &mut second, "d");
+
+        let actions = AnalyzerActionIter::new(vec![first, second]);
+
+        let result = actions.apply_non_conflicting(root.syntax().clone(), Applicability::Always);
+
+        assert_eq!(result.root.to_string(), "bd");
+
+        let names: Vec<_> = result
+            .skipped
+            .iter()
+            .map(|action| action.rule_name)
+            .collect();
+        assert_eq!(names, ["second"]);
+    }
+
+    #[test]
+    fn apply_non_conflicting_identical_range() {
+        let root = literals(&["a", "b", "c", "d"]);
+
+        let actions = AnalyzerActionIter::new(vec![
+            action(&root, "a1", Some("a")),
+            action(&root, "a2", Some("a")),
+            action(&root, "empty", None),
+        ]);
+
+        let result = actions.apply_non_conflicting(root.syntax().clone(), Applicability::Always);
+
+        assert_eq!(result.root.to_string(), "bcd");
+
+        let names: Vec<_> = result
+            .skipped
+            .iter()
+            .map(|action| action.rule_name)
+            .collect();
+        assert_eq!(names, ["a2", "empty"]);
+    }
+
+    #[test]
+    fn apply_non_conflicting_applicability() {
+        let root = literals(&["a", "b", "c", "d"]);
+
+        let actions = || {
+            let mut maybe_incorrect = action(&root, "maybe_incorrect", Some("d"));
+            maybe_incorrect.applicability = Applicability::MaybeIncorrect;
+
+            AnalyzerActionIter::new(vec![action(&root, "always", Some("a")), maybe_incorrect])
+        };
+
+        let result = actions().apply_non_conflicting(root.syntax().clone(), Applicability::Always);
+
+        assert_eq!(result.root.to_string(), "bcd");
+
+        let names: Vec<_> = result
+            .skipped
+            .iter()
+            .map(|action| action.rule_name)
+            .collect();
+        assert_eq!(names, ["maybe_incorrect"]);
+
+        let result =
+            actions().apply_non_conflicting(root.syntax().clone(), Applicability::MaybeIncorrect);
+
+        assert_eq!(result.root.to_string(), "bc");
+        assert!(result.skipped.is_empty());
+    }
 }
@@ -0,0 +1,442 @@
+use crate::SuppressionStyle;
+use rome_rowan::{
+    BatchMutation, Language, SyntaxNode, SyntaxToken, TextRange, TextSize, TriviaPieceKind,
+};
+
+/// Language-specific builder for the suppression actions of the lines where a `//` line comment
+/// can't be inserted, for example the lines starting inside of JSX children.
+///
+/// Languages that need it insert it in the [ServiceBag](crate::ServiceBag) of the analyzer.
+pub struct SuppressionHook<L: Language> {
+    pub block_suppression: BlockSuppression<L>,
+    pub requires_block_comments: RequiresBlockComments<L>,
+}
+
+/// Returns a [BatchMutation] inserting a block comment for each text of `comments` above the
+/// line containing `offset` and the empty range of the original document where the comments are
+/// inserted, or `None` if the suppression uses the default line comments.
+type BlockSuppression<L> = fn(
+    root: &SyntaxNode<L>,
+    offset: TextSize,
+    comments: &[String],
+) -> Option<(BatchMutation<L>, TextRange)>;
+
+/// Returns `true` if the suppression comments inserted above the line containing `offset` must be
+/// `/* */` block comments, for example because the code at `offset` is inside of a construct
+/// written on a single line, such as an object literal. The comments are still inserted on
+/// their own line.
+type RequiresBlockComments<L> = fn(root: &SyntaxNode<L>, offset: TextSize) -> bool;
+
+/// Range of code suppressed by a suppression comment
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SuppressionScope {
+    /// The comment suppresses the line following it
+    Line,
+    /// The comment suppresses the whole file. It's only taken into account
+    /// in the leading trivia of the first token of the file
+    File,
+}
+
+impl<L: Language> Clone for SuppressionHook<L> {
+    fn clone(&self) -> Self {
+        Self {
+            block_suppression: self.block_suppression,
+            requires_block_comments: self.requires_block_comments,
+        }
+    }
+}
+
+/// Creates a [BatchMutation] inserting a `// rome-ignore lint(<group>/<rule>)`
+/// comment on its own line, right above the line containing `offset`.
+///
+/// The comment is inserted in the leading trivia of the token starting the line,
+/// using the form of `style` and the indentation of that line: the suppression of a
+/// statement in the body of a switch case aligns with the statement, not the `case`
+/// keyword. The `hook` of the language takes over if the line requires block comments,
+/// or decides that the comments inserted above the line are `/* */` block comments.
+///
+/// If `todo` is provided, a `// TODO(<rule>): <todo>` comment is inserted above
+/// the suppression comment. The text of the TODO is collapsed to a single line
+/// so it can't escape the comment.
+///
+/// If the line above is a `rome-ignore` comment, the rule is appended to its categories
+/// instead of inserting new comments. Returns `None` if the comment already suppresses the rule.
+///
+/// Returns the mutation along with the range of the original document affected by it: the
+/// range of the merged comment, or the empty range where the comments are inserted.
+pub(crate) fn suppression_mutation<L: Language>(
+    root: &SyntaxNode<L>,
+    offset: TextSize,
+    group_name: &str,
+    rule_name: &str,
+    style: SuppressionStyle,
+    todo: Option<&str>,
+    hook: Option<SuppressionHook<L>>,
+) -> Option<(BatchMutation<L>, TextRange)> {
+    let mut comments = Vec::new();
+    if let Some(todo) = todo {
+        let todo = todo.split_whitespace().collect::<Vec<_>>().join(" ");
+        comments.push(format!("TODO({rule_name}): {}", todo.replace("*/", "* /")));
+    }
+    let ignore = match style {
+        SuppressionStyle::Node => "rome-ignore",
+        SuppressionStyle::NextLine => "rome-ignore-next-line",
+    };
+    comments.push(format!(
+        "{ignore} lint({group_name}/{rule_name}): <explanation>"
+    ));
+
+    let mut comment_kind = TriviaPieceKind::SingleLineComment;
+
+    if let Some(hook) = hook {
+        if let Some(suppression) = (hook.block_suppression)(root, offset, &comments) {
+            return Some(suppression);
+        }
+
+        if (hook.requires_block_comments)(root, offset) {
+            comment_kind = TriviaPieceKind::MultiLineComment;
+        }
+    }
+
+    let token = first_token_on_line(root.token_at_offset(offset).right_biased()?);
+
+    let comments: Vec<_> = comments
+        .iter()
+        .map(|comment| match comment_kind {
+            TriviaPieceKind::MultiLineComment => format!("/* {comment} */"),
+            _ => format!("// {comment}"),
+        })
+        .collect();
+
+    let leading_trivia = token.leading_trivia();
+    let pieces: Vec<_> = leading_trivia.pieces().collect();
+
+    // Split the leading trivia after its last newline: the comments are
+    // inserted between the two halves, using the indentation of the line
+    let line_start = pieces
+        .iter()
+        .rposition(|piece| piece.is_newline())
+        .map_or(0, |index| index + 1);
+    let (before, after) = pieces.split_at(line_start);
+
+    // Merge the rule in the suppression comment of the line above, if there's one
+    let previous_line_comment = before
+        .iter()
+        .enumerate()
+        .rev()
+        .skip(1)
+        .find(|(_, piece)| !piece.is_whitespace())
+        .filter(|(_, piece)| piece.is_comments());
+
+    if let Some((index, comment)) = previous_line_comment {
+        match merge_suppression_comment(comment.text(), group_name, rule_name) {
+            Some(MergedSuppression::AlreadySuppressed) => return None,
+            Some(MergedSuppression::Merged(text)) => {
+                let trivia = pieces.iter().enumerate().map(|(piece_index, piece)| {
+                    if piece_index == index {
+                        (piece.kind(), text.as_str())
+                    } else {
+                        (piece.kind(), piece.text())
+                    }
+                });
+
+                let next_token = token.with_leading_trivia(trivia);
+                let range = comment.text_range();
+
+                let mut mutation = BatchMutation::new(root.clone());
+                mutation.replace_token_discard_trivia(token, next_token);
+                return Some((mutation, range));
+            }
+            None => {}
+        }
+    }
+
+    let newline = before.last().map_or("\n", |piece| piece.text());
+    let indentation: Vec<_> = after
+        .iter()
+        .take_while(|piece| piece.is_whitespace())
+        .map(|piece| (piece.kind(), piece.text()))
+        .collect();
+
+    let mut trivia: Vec<_> = before
+        .iter()
+        .map(|piece| (piece.kind(), piece.text()))
+        .collect();
+
+    for comment in &comments {
+        trivia.extend(indentation.iter().copied());
+        trivia.push((comment_kind, comment.as_str()));
+        trivia.push((TriviaPieceKind::Newline, newline));
+    }
+
+    trivia.extend(after.iter().map(|piece| (piece.kind(), piece.text())));
+
+    // The comments are inserted before the indentation of the line
+    let range = TextRange::empty(
+        after
+            .first()
+            .map_or(token.text_trimmed_range().start(), |piece| {
+                piece.text_range().start()
+            }),
+    );
+
+    let next_token = token.with_leading_trivia(trivia);
+
+    let mut mutation = BatchMutation::new(root.clone());
+    mutation.replace_token_discard_trivia(token, next_token);
+    Some((mutation, range))
+}
+
+/// Creates a [BatchMutation] inserting a `// rome-ignore-all lint(<group>/<rule>)`
+/// comment on its own line at the top of the file.
+///
+/// The comment is inserted in the leading trivia of the first token of `root`,
+/// before any existing trivia. Returns the mutation along with the empty range
+/// at the start of the document where the comment is inserted.
+pub(crate) fn file_suppression_mutation<L: Language>(
+    root: &SyntaxNode<L>,
+    group_name: &str,
+    rule_name: &str,
+) -> Option<(BatchMutation<L>, TextRange)> {
+    let token = root.first_token()?;
+    let comment = format!("// rome-ignore-all lint({group_name}/{rule_name}): <explanation>");
+
+    let leading_trivia = token.leading_trivia();
+    let pieces: Vec<_> = leading_trivia.pieces().collect();
+
+    let mut trivia = vec![
+        (TriviaPieceKind::SingleLineComment, comment.as_str()),
+        (TriviaPieceKind::Newline, "\n"),
+    ];
+    trivia.extend(pieces.iter().map(|piece| (piece.kind(), piece.text())));
+
+    let range = TextRange::empty(token.text_range().start());
+    let next_token = token.with_leading_trivia(trivia);
+
+    let mut mutation = BatchMutation::new(root.clone());
+    mutation.replace_token_discard_trivia(token, next_token);
+    Some((mutation, range))
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum MergedSuppression {
+    /// The comment already suppresses the rule
+    AlreadySuppressed,
+    /// The text of the comment with the rule appended to its categories
+    Merged(String),
+}
+
+/// Appends `lint(<group>/<rule>)` to the categories of the suppression `comment`.
+///
+/// Returns `None` if `comment` isn't a `rome-ignore` comment.
+fn merge_suppression_comment(
+    comment: &str,
+    group_name: &str,
+    rule_name: &str,
+) -> Option<MergedSuppression> {
+    let content = comment
+        .strip_prefix("//")
+        .or_else(|| comment.strip_prefix("/*"))?
+        .trim_start();
+    let categories = content.strip_prefix("rome-ignore")?;
+    let categories = categories.strip_prefix("-next-line").unwrap_or(categories);
+
+    // Leave the other spellings such as `rome-ignore-all` untouched
+    if !categories.starts_with(|c: char| c == ':' || c.is_whitespace()) {
+        return None;
+    }
+
+    let categories_start = comment.len() - categories.len();
+
+    // The categories end at the first colon outside of parentheses
+    let mut depth = 0usize;
+    let end = categories.find(|c| {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ':' if depth == 0 => return true,
+            _ => {}
+        }
+        false
+    })?;
+    let categories = categories[..end].trim_end();
+
+    let group_filter = format!("lint({group_name})");
+    let rule_filter = format!("lint({group_name}/{rule_name})");
+    let is_suppressed = categories
+        .split_whitespace()
+        .any(|category| category == "lint" || category == group_filter || category == rule_filter);
+
+    if is_suppressed {
+        return Some(MergedSuppression::AlreadySuppressed);
+    }
+
+    let insert_at = categories_start + categories.len();
+    Some(MergedSuppression::Merged(format!(
+        "{} {rule_filter}{}",
+        &comment[..insert_at],
+        &comment[insert_at..]
+    )))
+}
+
+/// Returns the first token of the line `token` is on
+fn first_token_on_line<L: Language>(mut token: SyntaxToken<L>) -> SyntaxToken<L> {
+    while !token
+        .leading_trivia()
+        .pieces()
+        .any(|piece| piece.is_newline())
+    {
+        match token.prev_token() {
+            Some(prev_token) => token = prev_token,
+            None => break,
+        }
+    }
+
+    token
+}
+
+#[cfg(test)]
+mod tests {
+    use rome_rowan::{
+        raw_language::{RawLanguageKind, RawSyntaxTreeBuilder},
+        TextRange, TextSize, TriviaPiece, TriviaPieceKind,
+    };
+
+    use super::{merge_suppression_comment, suppression_mutation, MergedSuppression};
+    use crate::SuppressionStyle;
+
+    #[test]
+    fn todo_is_single_line() {
+        let mut builder = RawSyntaxTreeBuilder::new();
+        builder
+            .start_node(RawLanguageKind::ROOT)
+            .token(RawLanguageKind::STRING_TOKEN, "a")
+            .token(RawLanguageKind::STRING_TOKEN, "b")
+            .finish_node();
+        let root = builder.finish();
+
+        let (mutation, range) = suppression_mutation(
+            &root,
+            TextSize::from(1),
+            "group",
+            "rule",
+            SuppressionStyle::Node,
+            Some("first line\n  second\r\nline "),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            mutation.commit().to_string(),
+            "// TODO(rule): first line second line\n// rome-ignore lint(group/rule): <explanation>\nab"
+        );
+        assert_eq!(range, TextRange::empty(TextSize::from(0)));
+    }
+
+    #[test]
+    fn merge_into_empty_comment() {
+        assert_eq!(
+            merge_suppression_comment("// rome-ignore: reason", "group", "rule"),
+            Some(MergedSuppression::Merged(
+                "// rome-ignore lint(group/rule): reason".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn merge_into_single_rule_comment() {
+        assert_eq!(
+            merge_suppression_comment("// rome-ignore lint(group/other): reason", "group", "rule"),
+            Some(MergedSuppression::Merged(
+                "// rome-ignore lint(group/other) lint(group/rule): reason".to_string()
+            ))
+        );
+        assert_eq!(
+            merge_suppression_comment("/* rome-ignore format: reason */", "group", "rule"),
+            Some(MergedSuppression::Merged(
+                "/* rome-ignore format lint(group/rule): reason */".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn merge_into_multi_rule_comment() {
+        assert_eq!(
+            merge_suppression_comment(
+                "// rome-ignore-next-line lint(a/b) lint(c/d) : reason: with colon",
+                "group",
+                "rule"
+            ),
+            Some(MergedSuppression::Merged(
+                "// rome-ignore-next-line lint(a/b) lint(c/d) lint(group/rule) : reason: with colon"
+                    .to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn merge_already_suppressed() {
+        for comment in [
+            "// rome-ignore lint(group/rule): reason",
+            "// rome-ignore lint(a/b) lint(group/rule): reason",
+            "// rome-ignore lint(group): reason",
+            "// rome-ignore lint: reason",
+        ] {
+            assert_eq!(
+                merge_suppression_comment(comment, "group", "rule"),
+                Some(MergedSuppression::AlreadySuppressed)
+            );
+        }
+    }
+
+    #[test]
+    fn merge_into_comment_of_previous_line() {
+        let mut builder = RawSyntaxTreeBuilder::new();
+        builder
+            .start_node(RawLanguageKind::ROOT)
+            .token(RawLanguageKind::STRING_TOKEN, "a");
+        builder.token_with_trivia(
+            RawLanguageKind::STRING_TOKEN,
+            "\n// rome-ignore lint(group/other): reason\nb",
+            &[
+                TriviaPiece::new(TriviaPieceKind::Newline, 1),
+                TriviaPiece::new(TriviaPieceKind::SingleLineComment, 40),
+                TriviaPiece::new(TriviaPieceKind::Newline, 1),
+            ],
+            &[],
+        );
+        builder.finish_node();
+        let root = builder.finish();
+
+        let (mutation, range) = suppression_mutation(
+            &root,
+            TextSize::from(43),
+            "group",
+            "rule",
+            SuppressionStyle::Node,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            mutation.commit().to_string(),
+            "a\n// rome-ignore lint(group/other) lint(group/rule): reason\nb"
+        );
+        // The range of the merged comment
+        assert_eq!(range, TextRange::new(TextSize::from(2), TextSize::from(42)));
+
+        assert_eq!(
+            merge_suppression_comment("// not a suppression", "group", "rule"),
+            None
+        );
+        assert_eq!(
+            merge_suppression_comment(
+                "// rome-ignore-all lint(group/other): reason",
+                "group",
+                "rule"
+            ),
+            None
+        );
+    }
+}
@@ -310,6 +310,7 @@ fn process_messages(options: ProcessMessagesOptions) {
     let mut printed_diagnostics: u16 = 0;
     let mut not_printed_diagnostics = 0;
     let mut total_skipped_suggested_fixes = 0;
+    let mut total_suppressed_diagnostics = 0;
 
     let mut is_msg_open = true;
     let mut is_report_open = true;
@@ -343,6 +344,12 @@ fn process_messages(options: ProcessMessagesOptions) {
                 total_skipped_suggested_fixes += skipped_suggested_fixes;
             }
 
+            Message::SuppressedDiagnostics {
+                suppressed_diagnostics,
+            } => {
+                total_suppressed_diagnostics += suppressed_diagnostics;
+            }
+
             Message::Error(mut err) => {
                 if let Some(location) = err.location() {
                     if let v2::Resource::File(FilePath::FileId(file_id)) = location.resource {
@@ -544,6 +551,12 @@ fn process_messages(options: ProcessMessagesOptions) {
         })
     }
 
+    if total_suppressed_diagnostics > 0 {
+        console.log(markup! {
+            <Info>"Suppressed "{total_suppressed_diagnostics}" diagnostics with a suppression comment."</Info>
+        })
+    }
+
     if !mode.is_ci() && not_printed_diagnostics > 0 {
         console.log(markup! {
             <Warn>"The number of diagnostics exceeds the number allowed by Rome.\n"</Warn>
@@ -806,6 +819,12 @@ fn process_file(ctx: &TraversalOptions, path: &Path, file_id: FileId) -> FileRes
             _ => {}
         }
 
+        if result.suppressed_diagnostics > 0 && !ctx.execution.is_format() {
+            ctx.push_message(Message::SuppressedDiagnostics {
+                suppressed_diagnostics: result.suppressed_diagnostics,
+            });
+        }
+
         // In format mode the diagnostics have already been checked for errors
         // at this point, so they can just be dropped now since we don't want
         // to print syntax warnings for the format command
@@ -888,6 +907,10 @@ enum Message {
         /// Suggested fixes skipped during the lint traversal
         skipped_suggested_fixes: u32,
     },
+    SuppressedDiagnostics {
+        /// Diagnostics discarded because a suppression comment covers them
+        suppressed_diagnostics: u64,
+    },
     Error(Error),
     Diagnostics {
         name: String,
@@ -27,6 +27,10 @@ for(;true;);for(;true;);for(;true;);for(;true;);for(;true;);for(;true;);
 
 const NO_DEBUGGER: &str = "debugger;";
 
+const SUPPRESSED_DEBUGGER: &str = "// rome-ignore lint(correctness/noDebugger): test
+debugger;
+";
+
 const FIX_BEFORE: &str = "
 if(a != -0) {}
 ";
@@ -830,3 +834,28 @@ fn max_diagnostics() {
 
     assert_eq!(console.out_buffer.len(), 11);
 }
+
+#[test]
+fn suppressed_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), SUPPRESSED_DEBUGGER.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(vec![OsString::from("check"), file_path.as_os_str().into()]),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "suppressed_diagnostics",
+        fs,
+        console,
+        result,
+    ));
+}
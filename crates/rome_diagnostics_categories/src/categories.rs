@@ -83,6 +83,8 @@ define_dategories! {
     "lint/nursery/useExhaustiveDependencies": "https://docs.rome.tools/lint/rules/useExhaustiveDependencies",
     "lint/nursery/useCamelCase": "https://docs.rome.tools/lint/rules/useCamelCase",
     "lint/nursery/noBannedTypes":"https://docs.rome.tools/lint/rules/noBannedTypes",
+    "lint/nursery/useConst":"https://docs.rome.tools/lint/rules/useConst",
+    "lint/nursery/noMissingInitializer":"https://docs.rome.tools/lint/rules/noMissingInitializer",
 
     ;
 
@@ -97,6 +99,7 @@ define_dategories! {
     "parse",
     "parse/noSuperWithoutExtends",
 
+    "suppressions/misplacedFileSuppression",
     "suppressions/unknownGroup",
     "suppressions/unknownRule",
     // Used in tests and examples
@@ -1,7 +1,11 @@
 use super::{write, Arguments, FormatElement};
+use crate::format_element::document::Document;
 use crate::format_element::Interned;
 use crate::prelude::LineMode;
-use crate::{Format, FormatResult, FormatState};
+use crate::printer::{PrintedChunks, Printer};
+use crate::{
+    Format, FormatContext, FormatError, FormatOptions, FormatResult, FormatState, Printed,
+};
 use rustc_hash::FxHashMap;
 use std::any::{Any, TypeId};
 use std::fmt::Debug;
@@ -74,6 +78,17 @@ pub trait Buffer {
     /// If the passed snapshot id is a snapshot of another buffer OR
     /// if the snapshot is restored out of order
     fn restore_snapshot(&mut self, snapshot: BufferSnapshot);
+
+    /// Signals that the elements written so far are complete: their layout doesn't depend on the
+    /// elements that follow. A buffer streaming the document to an output, like [StreamingBuffer],
+    /// prints and drops these elements.
+    ///
+    /// Only call this at the top level of a document, outside of any recording or snapshot:
+    /// a [StreamingBuffer] fails with [FormatError::FlushedSnapshot] if a snapshot taken before
+    /// the flush gets restored. Does nothing by default.
+    fn flush(&mut self) -> FormatResult<()> {
+        Ok(())
+    }
 }
 
 /// Snapshot of a buffer state that can be restored at a later point.
@@ -163,6 +178,10 @@ impl<W: Buffer<Context = Context> + ?Sized, Context> Buffer for &mut W {
     fn restore_snapshot(&mut self, snapshot: BufferSnapshot) {
         (**self).restore_snapshot(snapshot)
     }
+
+    fn flush(&mut self) -> FormatResult<()> {
+        (**self).flush()
+    }
 }
 
 /// Vector backed [`Buffer`] implementation.
@@ -253,6 +272,180 @@ Make sure that you take and restore the snapshot in order and that this snapshot
     }
 }
 
+/// [`Buffer`] implementation that prints the written elements to an output whenever the
+/// elements are [flushed](Buffer::flush).
+///
+/// Flushing prints the elements written since the last flush as the next chunk of the document
+/// and drops them, keeping the memory used by the IR proportional to the largest flushed chunk
+/// rather than the whole document. The chunks are printed by printers resuming from the state
+/// of the previous chunk, so that the elements of a chunk can refer to the groups of the previous
+/// chunks. Flushing is a no-op while a tag is still open.
+///
+/// The flushed elements can't be removed from the output anymore: restoring a snapshot taken
+/// before a flush makes the next write or flush fail with [FormatError::FlushedSnapshot].
+pub struct StreamingBuffer<'a, Context> {
+    state: &'a mut FormatState<Context>,
+    elements: Vec<FormatElement>,
+    output: &'a mut dyn std::fmt::Write,
+    /// State of the printer carried over from one chunk to the next
+    chunks: PrintedChunks,
+    /// Number of start tags that haven't been closed yet
+    open_tags: usize,
+    /// Number of elements that have been printed and dropped
+    flushed_len: usize,
+    /// Set when a snapshot taken before the last flush has been restored
+    restored_flushed_snapshot: bool,
+}
+
+impl<'a, Context> StreamingBuffer<'a, Context> {
+    pub fn new(state: &'a mut FormatState<Context>, output: &'a mut dyn std::fmt::Write) -> Self {
+        Self {
+            state,
+            elements: Vec::new(),
+            output,
+            chunks: PrintedChunks::default(),
+            open_tags: 0,
+            flushed_len: 0,
+            restored_flushed_snapshot: false,
+        }
+    }
+
+    /// Returns an error if a snapshot taken before the last flush has been restored
+    fn check_snapshots(&self) -> FormatResult<()> {
+        if self.restored_flushed_snapshot {
+            Err(FormatError::FlushedSnapshot)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<Context> StreamingBuffer<'_, Context>
+where
+    Context: FormatContext,
+{
+    /// Prints the remaining elements and returns the source markers and verbatim ranges of the
+    /// printed document. The code of the result is empty, it has been written to the output.
+    pub fn finish(mut self) -> FormatResult<Printed> {
+        self.check_snapshots()?;
+
+        if !self.elements.is_empty() {
+            self.print_chunk()?;
+        }
+
+        Ok(self.chunks.finish())
+    }
+
+    /// Prints the elements written since the last flush to the output
+    fn print_chunk(&mut self) -> FormatResult<()> {
+        self.flushed_len += self.elements.len();
+
+        let mut document = Document::from(std::mem::take(&mut self.elements));
+        document.propagate_expand();
+
+        let print_options = self.state.context().options().as_print_options();
+        let code = Printer::new(print_options).print_chunk(&document, &mut self.chunks)?;
+
+        self.output
+            .write_str(&code)
+            .map_err(|_| FormatError::WriteError)
+    }
+}
+
+impl<Context> Debug for StreamingBuffer<'_, Context>
+where
+    Context: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamingBuffer")
+            .field("state", &self.state)
+            .field("elements", &self.elements)
+            .field("chunks", &self.chunks)
+            .field("open_tags", &self.open_tags)
+            .field("flushed_len", &self.flushed_len)
+            .field("restored_flushed_snapshot", &self.restored_flushed_snapshot)
+            .finish()
+    }
+}
+
+impl<Context> Buffer for StreamingBuffer<'_, Context>
+where
+    Context: FormatContext,
+{
+    type Context = Context;
+
+    fn write_element(&mut self, element: FormatElement) -> FormatResult<()> {
+        self.check_snapshots()?;
+
+        if let FormatElement::Tag(tag) = &element {
+            if tag.is_start() {
+                self.open_tags += 1;
+            } else {
+                self.open_tags = self.open_tags.saturating_sub(1);
+            }
+        }
+
+        self.elements.push(element);
+
+        Ok(())
+    }
+
+    fn elements(&self) -> &[FormatElement] {
+        &self.elements
+    }
+
+    fn state(&self) -> &FormatState<Self::Context> {
+        self.state
+    }
+
+    fn state_mut(&mut self) -> &mut FormatState<Self::Context> {
+        self.state
+    }
+
+    fn snapshot(&self) -> BufferSnapshot {
+        BufferSnapshot::position(self.flushed_len + self.elements.len())
+    }
+
+    fn restore_snapshot(&mut self, snapshot: BufferSnapshot) {
+        let position = snapshot.unwrap_position();
+
+        // The elements written since the snapshot have already been printed, fail the next write
+        if position < self.flushed_len {
+            self.restored_flushed_snapshot = true;
+            return;
+        }
+
+        let position = position - self.flushed_len;
+        assert!(
+            self.elements.len() >= position,
+            r#"Outdated snapshot. This buffer contains fewer elements than at the time the snapshot was taken.
+Make sure that you take and restore the snapshot in order and that this snapshot belongs to the current buffer."#
+        );
+
+        for element in &self.elements[position..] {
+            if let FormatElement::Tag(tag) = element {
+                if tag.is_start() {
+                    self.open_tags = self.open_tags.saturating_sub(1);
+                } else {
+                    self.open_tags += 1;
+                }
+            }
+        }
+
+        self.elements.truncate(position);
+    }
+
+    fn flush(&mut self) -> FormatResult<()> {
+        self.check_snapshots()?;
+
+        if self.open_tags > 0 || self.elements.is_empty() {
+            return Ok(());
+        }
+
+        self.print_chunk()
+    }
+}
+
 /// This struct wraps an existing buffer and emits a preamble text when the first text is written.
 ///
 /// This can be useful if you, for example, want to write some content if what gets written next isn't empty.
@@ -132,6 +132,47 @@ pub const fn empty_line() -> Line {
     Line::new(LineMode::Empty)
 }
 
+/// Forced empty lines. Inserts enough line breaks in the output for the previous and next element
+/// to be separated by `count` empty lines.
+///
+/// `empty_lines(0)` is a [hard_line_break] and `empty_lines(1)` an [empty_line]. Like [empty_line],
+/// consecutive empty lines don't add up: the elements are separated by the largest `count`.
+///
+/// # Examples
+///
+/// ```
+/// use rome_formatter::{format, format_args};
+/// use rome_formatter::prelude::*;
+///
+/// fn main() -> FormatResult<()> {
+/// let elements = format!(
+///     SimpleFormatContext::default(), [
+///     text("a"),
+///     empty_lines(2),
+///     text("b"),
+///     empty_lines(0),
+///     text("c"),
+///     empty_lines(2),
+///     empty_line(),
+///     text("d"),
+/// ])?;
+///
+/// assert_eq!(
+///     "a\n\n\nb\nc\n\n\nd",
+///     elements.print()?.as_code()
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub const fn empty_lines(count: u8) -> Line {
+    Line::new(match count {
+        0 => LineMode::Hard,
+        1 => LineMode::Empty,
+        count => LineMode::EmptyLines(count),
+    })
+}
+
 /// A line break if the enclosing `Group` doesn't fit on a single line, a space otherwise.
 ///
 /// # Examples
@@ -423,17 +464,57 @@ where
 {
     LineSuffix {
         content: Argument::new(inner),
+        reserved_width: 0,
     }
 }
 
 #[derive(Copy, Clone)]
 pub struct LineSuffix<'a, Context> {
     content: Argument<'a, Context>,
+    reserved_width: u32,
+}
+
+impl<Context> LineSuffix<'_, Context> {
+    /// Reserves `width` on the current line for the content of the line suffix when the printer
+    /// measures if some content fits, as if the content was printed at the position of the line suffix.
+    ///
+    /// By default, the content of a line suffix doesn't count towards the width of the line.
+    ///
+    /// ```
+    /// use rome_formatter::{format, format_args, LineWidth, SimpleFormatOptions};
+    /// use rome_formatter::prelude::*;
+    ///
+    /// # fn  main() -> FormatResult<()> {
+    /// let context = SimpleFormatContext::new(SimpleFormatOptions {
+    ///     line_width: LineWidth::try_from(10).unwrap(),
+    ///     ..SimpleFormatOptions::default()
+    /// });
+    ///
+    /// let elements = format!(context, [
+    ///     group(&format_args![
+    ///         text("[a,"),
+    ///         line_suffix(&text(" // long comment")).with_reserved_width(16),
+    ///         soft_line_break_or_space(),
+    ///         text("b]"),
+    ///     ])
+    /// ])?;
+    ///
+    /// assert_eq!(
+    ///     "[a, // long comment\nb]",
+    ///     elements.print()?.as_code()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_reserved_width(mut self, width: u32) -> Self {
+        self.reserved_width = width;
+        self
+    }
 }
 
 impl<Context> Format<Context> for LineSuffix<'_, Context> {
     fn fmt(&self, f: &mut Formatter<Context>) -> FormatResult<()> {
-        f.write_element(FormatElement::Tag(StartLineSuffix))?;
+        f.write_element(FormatElement::Tag(StartLineSuffix(self.reserved_width)))?;
         Arguments::from(&self.content).fmt(f)?;
         f.write_element(FormatElement::Tag(EndLineSuffix))
     }
@@ -595,6 +676,48 @@ impl<Context> Format<Context> for Space {
     }
 }
 
+/// Inserts `count` spaces, for example to pad a comment or to align an operator.
+///
+/// The printer collapses consecutive [space]s into one, this builder writes the spaces after
+/// the first one as text so that all of them are printed. Nothing is written if `count` is `0`.
+///
+/// # Examples
+///
+/// ```
+/// use rome_formatter::format;
+/// use rome_formatter::prelude::*;
+///
+/// # fn main() -> FormatResult<()> {
+/// let elements = format!(SimpleFormatContext::default(), [text("a"), spaces(3), text("b")])?;
+///
+/// assert_eq!("a   b", elements.print()?.as_code());
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub const fn spaces(count: usize) -> Spaces {
+    Spaces { count }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Spaces {
+    count: usize,
+}
+
+impl<Context> Format<Context> for Spaces {
+    fn fmt(&self, f: &mut Formatter<Context>) -> FormatResult<()> {
+        if self.count > 0 {
+            f.write_element(FormatElement::Space)?;
+        }
+
+        for _ in 1..self.count {
+            f.write_element(FormatElement::StaticText { text: " " })?;
+        }
+
+        Ok(())
+    }
+}
+
 /// It adds a level of indentation to the given content
 ///
 /// It doesn't add any line breaks at the edges of the content, meaning that
@@ -2043,6 +2166,8 @@ pub struct JoinNodesBuilder<'fmt, 'buf, Separator, Context> {
     separator: Separator,
     fmt: &'fmt mut Formatter<'buf, Context>,
     has_elements: bool,
+    /// Whether to [flush](Buffer::flush) the buffer after writing a separator
+    flush_after_separators: bool,
 }
 
 impl<'fmt, 'buf, Separator, Context> JoinNodesBuilder<'fmt, 'buf, Separator, Context>
@@ -2055,9 +2180,19 @@ where
             separator,
             fmt,
             has_elements: false,
+            flush_after_separators: false,
         }
     }
 
+    /// [Flushes](Buffer::flush) the buffer after each separator, allowing a streaming buffer to
+    /// print the preceding nodes before formatting the next node.
+    ///
+    /// Only use this for lists at the top level of a document with hard line separators.
+    pub fn flush_after_separators(mut self) -> Self {
+        self.flush_after_separators = true;
+        self
+    }
+
     /// Adds a new node with the specified formatted content to the output, respecting any new lines
     /// that appear before the node in the input source.
     pub fn entry<L: Language>(&mut self, node: &SyntaxNode<L>, content: &dyn Format<Context>) {
@@ -2068,6 +2203,10 @@ where
                 } else {
                     self.separator.fmt(self.fmt)?;
                 }
+
+                if self.flush_after_separators {
+                    self.fmt.flush()?;
+                }
             }
 
             self.has_elements = true;
@@ -79,11 +79,11 @@ mod builder;
 mod map;
 
 use self::{builder::CommentsBuilderVisitor, map::CommentsMap};
-use crate::{TextSize, TransformSourceMap};
+use crate::{TextRange, TextSize, TransformSourceMap};
+use once_cell::unsync::OnceCell;
 use rome_rowan::syntax::SyntaxElementKey;
 use rome_rowan::{Language, SyntaxNode, SyntaxToken, SyntaxTriviaPieceComments};
 use rustc_hash::FxHashSet;
-#[cfg(debug_assertions)]
 use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 
@@ -166,7 +166,6 @@ pub struct SourceComment<L: Language> {
     pub(crate) kind: CommentKind,
 
     /// Whether the comment has been formatted or not.
-    #[cfg(debug_assertions)]
     pub(crate) formatted: Cell<bool>,
 }
 
@@ -232,12 +231,7 @@ impl<L: Language> SourceComment<L> {
         self.kind
     }
 
-    #[cfg(not(debug_assertions))]
-    #[inline(always)]
-    pub fn mark_formatted(&self) {}
-
     /// Marks the comment as formatted
-    #[cfg(debug_assertions)]
     pub fn mark_formatted(&self) {
         self.formatted.set(true)
     }
@@ -460,7 +454,6 @@ impl<L: Language> From<DecoratedComment<L>> for SourceComment<L> {
             lines_after: decorated.lines_after,
             piece: decorated.comment,
             kind: decorated.kind,
-            #[cfg(debug_assertions)]
             formatted: Cell::new(false),
         }
     }
@@ -768,6 +761,38 @@ pub trait CommentStyle: Default {
     /// Returns the (kind)[CommentKind] of the comment
     fn get_comment_kind(comment: &SyntaxTriviaPieceComments<Self::Language>) -> CommentKind;
 
+    /// Returns `true` if `comment` is a block comment whose lines form an aligned frame,
+    /// for example an ASCII-framed license banner.
+    ///
+    /// Framed comments are printed verbatim: their lines are neither re-aligned nor re-indented.
+    /// Implementations must be conservative because a false positive keeps a regular comment unformatted.
+    ///
+    /// The default implementation returns `false`.
+    fn is_framed_block(_comment: &SyntaxTriviaPieceComments<Self::Language>) -> bool {
+        false
+    }
+
+    /// Returns `true` if `comment` is a directive for another tool, for example a pragma
+    /// disabling a lint rule for the next line.
+    ///
+    /// Directive comments are printed verbatim and trailing directive comments stay in front of
+    /// the code following them instead of being moved to the end of the line.
+    ///
+    /// The default implementation returns `false`.
+    fn is_directive(_comment: &SyntaxTriviaPieceComments<Self::Language>) -> bool {
+        false
+    }
+
+    /// Returns `true` if `comment` is a legal comment, like a license header, that minifiers
+    /// and other tools must retain.
+    ///
+    /// Leading legal comments are always followed by a line break.
+    ///
+    /// The default implementation returns `false`.
+    fn is_legal_comment(_comment: &SyntaxTriviaPieceComments<Self::Language>) -> bool {
+        false
+    }
+
     /// Determines the placement of `comment`.
     ///
     /// The default implementation returns [CommentPlacement::Default].
@@ -823,6 +848,8 @@ impl<L: Language> Comments<L> {
 
                 comments,
                 with_skipped: skipped,
+                verbatim_ranges: RefCell::default(),
+                dropped_comments: OnceCell::new(),
                 #[cfg(debug_assertions)]
                 checked_suppressions: RefCell::new(Default::default()),
             }),
@@ -928,6 +955,27 @@ impl<L: Language> Comments<L> {
             .any(|comment| is_suppression(comment.piece().text()))
     }
 
+    /// Marks the comments in `range` as consumed by a node that is formatted verbatim.
+    ///
+    /// The verbatim node prints its source text as is, including all the comments inside of `range`,
+    /// even comments that are attached to a node outside of the verbatim node.
+    /// The comment builders skip consumed comments to prevent printing them a second time.
+    pub fn mark_verbatim_range(&self, range: TextRange) {
+        self.data.verbatim_ranges.borrow_mut().push(range);
+    }
+
+    /// Returns `true` if `comment` is inside of the source text of a node formatted verbatim
+    /// and must not be formatted by the comment builders.
+    pub fn is_consumed_by_verbatim(&self, comment: &SourceComment<L>) -> bool {
+        let comment_range = comment.piece().text_range();
+
+        self.data
+            .verbatim_ranges
+            .borrow()
+            .iter()
+            .any(|range| range.contains_range(comment_range))
+    }
+
     #[cfg(not(debug_assertions))]
     #[inline(always)]
     pub fn mark_suppression_checked(&self, _: &SyntaxNode<L>) {}
@@ -973,6 +1021,32 @@ Node:
         }
     }
 
+    /// Returns the comments that the formatter dropped because no formatting rule formatted them.
+    ///
+    /// The dropped comments are collected once the whole tree has been formatted, and are
+    /// only available on the formatting result, through
+    /// [Formatted::dropped_comments](crate::Formatted::dropped_comments).
+    /// Formatting fails in debug builds when it drops comments, unless
+    /// [CstFormatContext::is_strict_comments](crate::CstFormatContext::is_strict_comments) returns `false`.
+    pub fn dropped_comments(&self) -> &[SourceComment<L>] {
+        self.data
+            .dropped_comments
+            .get()
+            .map_or(&[], |comments| comments.as_slice())
+    }
+
+    /// Collects the comments that haven't been formatted, see [Comments::dropped_comments].
+    pub(crate) fn collect_dropped_comments(&self) {
+        self.data.dropped_comments.get_or_init(|| {
+            self.data
+                .comments
+                .all_parts()
+                .filter(|comment| !comment.formatted.get())
+                .cloned()
+                .collect()
+        });
+    }
+
     #[inline(always)]
     #[cfg(not(debug_assertions))]
     pub(crate) fn assert_formatted_all_comments(&self) {}
@@ -1035,6 +1109,14 @@ struct CommentsData<L: Language> {
     comments: CommentsMap<SyntaxElementKey, SourceComment<L>>,
     with_skipped: FxHashSet<SyntaxElementKey>,
 
+    /// The ranges of the nodes that have been formatted verbatim.
+    /// See [Comments::mark_verbatim_range].
+    verbatim_ranges: RefCell<Vec<TextRange>>,
+
+    /// The comments that haven't been formatted.
+    /// See [Comments::dropped_comments].
+    dropped_comments: OnceCell<Vec<SourceComment<L>>>,
+
     /// Stores all nodes for which [Comments::is_suppressed] has been called.
     /// This index of nodes that have been checked if they have a suppression comments is used to
     /// detect format implementations that manually format a child node without previously checking if
@@ -1054,6 +1136,8 @@ impl<L: Language> Default for CommentsData<L> {
             is_suppression: |_| false,
             comments: Default::default(),
             with_skipped: Default::default(),
+            verbatim_ranges: Default::default(),
+            dropped_comments: Default::default(),
             #[cfg(debug_assertions)]
             checked_suppressions: Default::default(),
         }
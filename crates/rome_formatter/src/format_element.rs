@@ -101,12 +101,23 @@ pub enum LineMode {
     Hard,
     /// See [crate::builders::empty_line] for documentation.
     Empty,
+    /// See [crate::builders::empty_lines] for documentation.
+    EmptyLines(u8),
 }
 
 impl LineMode {
     pub const fn is_hard(&self) -> bool {
         matches!(self, LineMode::Hard)
     }
+
+    /// Returns the number of empty lines separating the elements before and after the line break
+    pub const fn empty_lines(&self) -> u8 {
+        match self {
+            LineMode::SoftOrSpace | LineMode::Soft | LineMode::Hard => 0,
+            LineMode::Empty => 1,
+            LineMode::EmptyLines(count) => *count,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -240,7 +251,10 @@ impl FormatElements for FormatElement {
         match self {
             FormatElement::ExpandParent => true,
             FormatElement::Tag(Tag::StartGroup(group)) => !group.mode().is_flat(),
-            FormatElement::Line(line_mode) => matches!(line_mode, LineMode::Hard | LineMode::Empty),
+            FormatElement::Line(line_mode) => matches!(
+                line_mode,
+                LineMode::Hard | LineMode::Empty | LineMode::EmptyLines(_)
+            ),
             FormatElement::StaticText { text } => text.contains('\n'),
             FormatElement::DynamicText { text, .. } => text.contains('\n'),
             FormatElement::SyntaxTokenTextSlice { slice, .. } => slice.contains('\n'),
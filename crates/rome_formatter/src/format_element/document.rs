@@ -23,6 +23,10 @@ impl Document {
     /// Sets [`expand`](tag::Group::expand) to [`GroupMode::Propagated`] if the group contains any of:
     /// * a group with [`expand`](tag::Group::expand) set to [GroupMode::Propagated] or [GroupMode::Expand].
     /// * a non-soft [line break](FormatElement::Line) with mode [LineMode::Hard], [LineMode::Empty], or [LineMode::Literal].
+    ///
+    /// Sets [`expand`](tag::Group::expand) to [`GroupMode::PropagatedFromExpandParent`] if the group
+    /// doesn't contain any of the above but contains:
+    /// * a group with [`expand`](tag::Group::expand) set to [GroupMode::PropagatedFromExpandParent].
     /// * a [FormatElement::ExpandParent]
     ///
     /// [`BestFitting`] elements act as expand boundaries, meaning that the fact that a
@@ -36,27 +40,43 @@ impl Document {
             BestFitting,
         }
 
-        fn expand_parent(enclosing: &[Enclosing]) {
+        /// Why some content expands, ordered from the weakest to the strongest reason.
+        #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+        enum Expands {
+            No,
+            ExpandParent,
+            Yes,
+        }
+
+        fn expand_parent(enclosing: &[Enclosing], expands: Expands) {
             if let Some(Enclosing::Group(group)) = enclosing.last() {
-                group.propagate_expand();
+                match expands {
+                    Expands::No => {}
+                    Expands::ExpandParent => group.propagate_expand_parent(),
+                    Expands::Yes => group.propagate_expand(),
+                }
             }
         }
 
         fn propagate_expands<'a>(
             elements: &'a [FormatElement],
             enclosing: &mut Vec<Enclosing<'a>>,
-            checked_interned: &mut FxHashMap<&'a Interned, bool>,
-        ) -> bool {
-            let mut expands = false;
+            checked_interned: &mut FxHashMap<&'a Interned, Expands>,
+        ) -> Expands {
+            let mut expands = Expands::No;
             for element in elements {
                 let element_expands = match element {
                     FormatElement::Tag(Tag::StartGroup(group)) => {
                         enclosing.push(Enclosing::Group(group));
-                        false
+                        Expands::No
                     }
                     FormatElement::Tag(Tag::EndGroup) => match enclosing.pop() {
-                        Some(Enclosing::Group(group)) => !group.mode().is_flat(),
-                        _ => false,
+                        Some(Enclosing::Group(group)) => match group.mode() {
+                            GroupMode::Flat => Expands::No,
+                            GroupMode::PropagatedFromExpandParent => Expands::ExpandParent,
+                            GroupMode::Expand | GroupMode::Propagated => Expands::Yes,
+                        },
+                        _ => Expands::No,
                     },
                     FormatElement::Interned(interned) => match checked_interned.get(interned) {
                         Some(interned_expands) => *interned_expands,
@@ -75,21 +95,25 @@ impl Document {
                         }
 
                         // Best fitting acts as a boundary
-                        expands = false;
+                        expands = Expands::No;
                         enclosing.pop();
                         continue;
                     }
-                    FormatElement::StaticText { text } => text.contains('\n'),
-                    FormatElement::DynamicText { text, .. } => text.contains('\n'),
-                    FormatElement::SyntaxTokenTextSlice { slice, .. } => slice.contains('\n'),
-                    FormatElement::ExpandParent
-                    | FormatElement::Line(LineMode::Hard | LineMode::Empty) => true,
-                    _ => false,
+                    FormatElement::StaticText { text } if text.contains('\n') => Expands::Yes,
+                    FormatElement::DynamicText { text, .. } if text.contains('\n') => Expands::Yes,
+                    FormatElement::SyntaxTokenTextSlice { slice, .. } if slice.contains('\n') => {
+                        Expands::Yes
+                    }
+                    FormatElement::Line(
+                        LineMode::Hard | LineMode::Empty | LineMode::EmptyLines(_),
+                    ) => Expands::Yes,
+                    FormatElement::ExpandParent => Expands::ExpandParent,
+                    _ => Expands::No,
                 };
 
-                if element_expands {
-                    expands = true;
-                    expand_parent(enclosing)
+                if element_expands != Expands::No {
+                    expands = expands.max(element_expands);
+                    expand_parent(enclosing, element_expands)
                 }
             }
 
@@ -97,7 +121,7 @@ impl Document {
         }
 
         let mut enclosing: Vec<Enclosing> = Vec::new();
-        let mut interned: FxHashMap<&Interned, bool> = FxHashMap::default();
+        let mut interned: FxHashMap<&Interned, Expands> = FxHashMap::default();
         propagate_expands(self, &mut enclosing, &mut interned);
     }
 }
@@ -166,6 +190,7 @@ impl FormatOptions for IrFormatOptions {
             print_width: self.line_width().into(),
             line_ending: LineEnding::LineFeed,
             indent_style: IndentStyle::Space(2),
+            measurement_mode: false,
         }
     }
 }
@@ -230,6 +255,16 @@ impl Format<IrFormatContext> for &[FormatElement] {
                     LineMode::Empty => {
                         write!(f, [text("empty_line")])?;
                     }
+                    LineMode::EmptyLines(count) => {
+                        write!(
+                            f,
+                            [
+                                text("empty_lines("),
+                                dynamic_text(&count.to_string(), TextSize::default()),
+                                text(")")
+                            ]
+                        )?;
+                    }
                 },
                 FormatElement::ExpandParent => {
                     write!(f, [text("expand_parent")])?;
@@ -369,8 +404,24 @@ impl Format<IrFormatContext> for &[FormatElement] {
                             )?;
                         }
 
-                        StartLineSuffix => {
+                        StartLineSuffix(reserved_width) => {
                             write!(f, [text("line_suffix(")])?;
+
+                            if *reserved_width > 0 {
+                                write!(
+                                    f,
+                                    [
+                                        text("reserved_width:"),
+                                        space(),
+                                        dynamic_text(
+                                            &reserved_width.to_string(),
+                                            TextSize::default()
+                                        ),
+                                        text(","),
+                                        space(),
+                                    ]
+                                )?;
+                            }
                         }
 
                         StartVerbatim(_) => {
@@ -399,7 +450,7 @@ impl Format<IrFormatContext> for &[FormatElement] {
                                 GroupMode::Expand => {
                                     write!(f, [text("expand: true,"), space()])?;
                                 }
-                                GroupMode::Propagated => {
+                                GroupMode::Propagated | GroupMode::PropagatedFromExpandParent => {
                                     write!(f, [text("expand: propagated,"), space()])?;
                                 }
                             }
@@ -546,7 +597,7 @@ impl FormatElements for [FormatElement] {
             match element {
                 // Line suffix
                 // Ignore if any of its content breaks
-                FormatElement::Tag(StartLineSuffix) => {
+                FormatElement::Tag(StartLineSuffix(_)) => {
                     ignore_depth += 1;
                 }
                 FormatElement::Tag(EndLineSuffix) => {
@@ -56,8 +56,11 @@ pub enum Tag {
     StartEntry,
     EndEntry,
 
-    /// Delay the printing of its content until the next line break
-    StartLineSuffix,
+    /// Delay the printing of its content until the next line break.
+    ///
+    /// The value is the width reserved on the current line when measuring if some content fits,
+    /// see [crate::builders::LineSuffix::with_reserved_width].
+    StartLineSuffix(u32),
     EndLineSuffix,
 
     /// A token that tracks tokens/nodes that are printed as verbatim.
@@ -85,7 +88,7 @@ impl Tag {
                 | Tag::StartIndentIfGroupBreaks(_)
                 | Tag::StartFill
                 | Tag::StartEntry
-                | Tag::StartLineSuffix
+                | Tag::StartLineSuffix(_)
                 | Tag::StartVerbatim(_)
                 | Tag::StartLabelled(_)
         )
@@ -108,7 +111,7 @@ impl Tag {
             StartIndentIfGroupBreaks(_) | EndIndentIfGroupBreaks => TagKind::IndentIfGroupBreaks,
             StartFill | EndFill => TagKind::Fill,
             StartEntry | EndEntry => TagKind::Entry,
-            StartLineSuffix | EndLineSuffix => TagKind::LineSuffix,
+            StartLineSuffix(_) | EndLineSuffix => TagKind::LineSuffix,
             StartVerbatim(_) | EndVerbatim => TagKind::Verbatim,
             StartLabelled(_) | EndLabelled => TagKind::Labelled,
         }
@@ -145,6 +148,11 @@ pub enum GroupMode {
 
     /// Expand mode has been propagated from an enclosing group to this group.
     Propagated,
+
+    /// Expand mode has been propagated to this group only because it contains an
+    /// [expand_parent](crate::builders::expand_parent). The printer prints the group
+    /// in flat mode if it is in [measurement mode](crate::PrinterOptions::measurement_mode).
+    PropagatedFromExpandParent,
 }
 
 impl GroupMode {
@@ -182,11 +190,20 @@ impl Group {
     }
 
     pub fn propagate_expand(&self) {
-        if self.mode.get() == GroupMode::Flat {
+        if matches!(
+            self.mode.get(),
+            GroupMode::Flat | GroupMode::PropagatedFromExpandParent
+        ) {
             self.mode.set(GroupMode::Propagated)
         }
     }
 
+    pub fn propagate_expand_parent(&self) {
+        if self.mode.get() == GroupMode::Flat {
+            self.mode.set(GroupMode::PropagatedFromExpandParent)
+        }
+    }
+
     pub fn id(&self) -> Option<GroupId> {
         self.id
     }
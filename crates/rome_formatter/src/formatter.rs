@@ -274,6 +274,10 @@ impl<Context> Buffer for Formatter<'_, Context> {
     fn restore_snapshot(&mut self, snapshot: BufferSnapshot) {
         self.buffer.restore_snapshot(snapshot)
     }
+
+    fn flush(&mut self) -> FormatResult<()> {
+        self.buffer.flush()
+    }
 }
 
 /// Snapshot of the formatter state  used to handle backtracking if
@@ -35,6 +35,7 @@ pub mod prelude;
 pub mod printed_tokens;
 pub mod printer;
 mod source_map;
+pub mod token;
 pub mod trivia;
 mod verbatim;
 
@@ -49,7 +50,7 @@ use crate::printer::{Printer, PrinterOptions};
 pub use arguments::{Argument, Arguments};
 pub use buffer::{
     Buffer, BufferExtensions, BufferSnapshot, Inspect, PreambleBuffer, RemoveSoftLinesBuffer,
-    VecBuffer,
+    StreamingBuffer, VecBuffer,
 };
 pub use builders::BestFitting;
 
@@ -58,10 +59,12 @@ use crate::comments::{CommentStyle, Comments, SourceComment};
 pub use format_element::{normalize_newlines, FormatElement, LINE_TERMINATORS};
 pub use group_id::GroupId;
 use rome_rowan::{
-    Language, SyntaxElement, SyntaxError, SyntaxNode, SyntaxResult, SyntaxToken, SyntaxTriviaPiece,
-    TextLen, TextRange, TextSize, TokenAtOffset,
+    Direction, Language, SyntaxElement, SyntaxError, SyntaxNode, SyntaxResult, SyntaxToken,
+    SyntaxTriviaPiece, TextLen, TextRange, TextSize, TokenAtOffset,
 };
 pub use source_map::{TransformSourceMap, TransformSourceMapBuilder};
+use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::error::Error;
 use std::num::ParseIntError;
 use std::str::FromStr;
@@ -116,6 +119,92 @@ impl std::fmt::Display for IndentStyle {
     }
 }
 
+/// Infers the [IndentStyle] of a file from the leading whitespace of its lines.
+///
+/// The style is [IndentStyle::Tab] if more lines are indented with tabs than with spaces, and
+/// [IndentStyle::Space] otherwise, with the most common indentation increase between two
+/// consecutive lines as its width. Lines mixing tabs and spaces are ignored.
+///
+/// Returns `fallback` if the file has no indented lines or as many lines indented with tabs
+/// as with spaces. The width of `fallback` is used if the file's indentation never increases.
+pub fn detect_indent_style<L: Language>(
+    root: &SyntaxNode<L>,
+    fallback: IndentStyle,
+) -> IndentStyle {
+    let mut tab_lines = 0usize;
+    let mut space_lines = 0usize;
+    // Number of lines for each indentation increase of 1 to 16 spaces
+    let mut increases = [0usize; 16];
+    let mut previous_width = 0;
+
+    for token in root.descendants_tokens(Direction::Next) {
+        let pieces: Vec<_> = token.leading_trivia().pieces().collect();
+
+        for (index, piece) in pieces.iter().enumerate() {
+            if !piece.is_newline() {
+                continue;
+            }
+
+            let (indent, next) = match pieces.get(index + 1) {
+                Some(whitespace) if whitespace.is_whitespace() => {
+                    (whitespace.text(), pieces.get(index + 2))
+                }
+                next => ("", next),
+            };
+
+            // Skip empty lines
+            if next.map_or(false, |next| next.is_newline()) {
+                continue;
+            }
+
+            if indent.starts_with('\t') {
+                if indent.bytes().all(|byte| byte == b'\t') {
+                    tab_lines += 1;
+                }
+                continue;
+            }
+
+            if !indent.bytes().all(|byte| byte == b' ') {
+                continue;
+            }
+
+            let width = indent.len();
+            if width > 0 {
+                space_lines += 1;
+            }
+
+            if let Some(count) = width
+                .checked_sub(previous_width + 1)
+                .and_then(|increase| increases.get_mut(increase))
+            {
+                *count += 1;
+            }
+
+            previous_width = width;
+        }
+    }
+
+    match tab_lines.cmp(&space_lines) {
+        Ordering::Equal => fallback,
+        Ordering::Greater => IndentStyle::Tab,
+        Ordering::Less => {
+            let most_common = increases
+                .iter()
+                .enumerate()
+                .filter(|(_, count)| **count > 0)
+                // Prefer the smallest increase if several are equally common
+                .rev()
+                .max_by_key(|(_, count)| **count);
+
+            match (most_common, fallback) {
+                (Some((increase, _)), _) => IndentStyle::Space(increase as u8 + 1),
+                (None, IndentStyle::Space(width)) => IndentStyle::Space(width),
+                (None, IndentStyle::Tab) => IndentStyle::Space(IndentStyle::DEFAULT_SPACES),
+            }
+        }
+    }
+}
+
 /// Validated value for the `line_width` formatter options
 ///
 /// The allowed range of values is 1..=320
@@ -239,6 +328,47 @@ pub trait CstFormatContext: FormatContext {
 
     /// Returns a reference to the program's comments.
     fn comments(&self) -> &Comments<Self::Language>;
+
+    /// Returns `true` if formatting must fail in debug builds when the formatter drops comments (the default).
+    ///
+    /// Returning `false` allows callers to report or re-insert the dropped comments
+    /// retrieved with [Formatted::dropped_comments].
+    fn is_strict_comments(&self) -> bool {
+        true
+    }
+
+    /// Returns the maximum number of consecutive empty lines preserved around comments
+    /// and skipped token trivia. Defaults to 1.
+    fn max_empty_lines(&self) -> u8 {
+        1
+    }
+
+    /// Returns `true` if dangling block comments force their enclosing structure to break,
+    /// for example to format `[/* comment */]` on three lines. Dangling line comments always
+    /// force a break. Defaults to `false`, the structure breaks only if the comments don't fit.
+    fn dangling_comments_force_break(&self) -> bool {
+        false
+    }
+
+    /// Returns the number of spaces between a trailing comment and the code it trails on the same line,
+    /// for example `2` for `a;  // comment`. Block comments followed by code on the same line,
+    /// like `a /* comment */, b`, are always separated by a single space. Defaults to 1.
+    fn trailing_comment_leading_spaces(&self) -> usize {
+        1
+    }
+
+    /// Returns the text to print instead of the trimmed text of `token`, for example to normalize
+    /// the casing of a numeric literal from `0XFF` to `0xff`. Returns `None` to print the token
+    /// exactly as written in the source (the default).
+    ///
+    /// Used by [format_trimmed_token](crate::trivia::format_trimmed_token) and therefore by
+    /// every token formatted as is. A borrowed text must be equal to the trimmed text of `token`.
+    fn normalize_token_text<'a>(
+        &self,
+        #[allow(unused_variables)] token: &'a SyntaxToken<Self::Language>,
+    ) -> Option<Cow<'a, str>> {
+        None
+    }
 }
 
 #[derive(Debug, Default, Eq, PartialEq)]
@@ -326,6 +456,19 @@ impl<Context> Formatted<Context> {
     }
 }
 
+impl<Context> Formatted<Context>
+where
+    Context: CstFormatContext,
+{
+    /// Returns the comments that the formatter dropped, see [Comments::dropped_comments].
+    ///
+    /// The comments are only known to be dropped once the whole tree has been formatted,
+    /// which is why they're exposed on the result rather than on the [Formatter].
+    pub fn dropped_comments(&self) -> &[SourceComment<Context::Language>] {
+        self.context.comments().dropped_comments()
+    }
+}
+
 impl<Context> Formatted<Context>
 where
     Context: FormatContext,
@@ -576,6 +719,17 @@ pub enum FormatError {
     /// This error should not be raised if there's no outer [`Format`] handling the poor layout error,
     /// avoiding that formatting of the whole document fails.
     PoorLayout,
+
+    /// Writing the printed document to the output failed.
+    WriteError,
+
+    /// A snapshot taken before a [StreamingBuffer] flushed its elements to the output has been
+    /// restored, but the elements written after the snapshot have already been printed.
+    FlushedSnapshot,
+
+    /// The syntax tree is nested deeper than the maximum depth allowed by the formatter,
+    /// formatting it could overflow the stack.
+    DepthExceeded,
 }
 
 impl std::fmt::Display for FormatError {
@@ -590,6 +744,13 @@ impl std::fmt::Display for FormatError {
             FormatError::PoorLayout => {
                 std::write!(fmt, "Poor layout: The formatter wasn't able to pick a good layout for your document. This is an internal Rome error. Please report if necessary.")
             }
+            FormatError::WriteError => fmt.write_str("failed to write the formatted output"),
+            FormatError::FlushedSnapshot => fmt.write_str(
+                "restored a snapshot of content that has already been written to the output",
+            ),
+            FormatError::DepthExceeded => {
+                fmt.write_str("the syntax tree is nested too deeply to be formatted")
+            }
         }
     }
 }
@@ -1029,33 +1190,86 @@ pub fn format_node<L: FormatLanguage>(
     language: L,
 ) -> FormatResult<Formatted<L::Context>> {
     tracing::trace_span!("format_node").in_scope(move || {
-        let (root, source_map) = match language.transform(root) {
-            Some((root, source_map)) => (root, Some(source_map)),
-            None => (root.clone(), None),
+        let (document, context) = format_root(root, language, |state, format_node| {
+            let mut buffer = VecBuffer::new(state);
+
+            write!(buffer, [format_node])?;
+
+            let mut document = Document::from(buffer.into_vec());
+            document.propagate_expand();
+
+            Ok(document)
+        })?;
+
+        Ok(Formatted::new(document, context))
+    })
+}
+
+/// Formats a syntax node file and writes the printed result to `output`.
+///
+/// Unlike [format_node], this doesn't build the document for the whole file up-front. Formats
+/// that [flush](Buffer::flush) the buffer, for example after each top-level statement, get
+/// their content printed to `output` and freed before formatting the rest of the file.
+/// This keeps the memory usage low when formatting very large files.
+///
+/// Returns the source markers and verbatim ranges of the printed code, the code of the
+/// result is empty.
+pub fn format_node_to_writer<L: FormatLanguage>(
+    root: &SyntaxNode<L::SyntaxLanguage>,
+    language: L,
+    output: &mut dyn std::fmt::Write,
+) -> FormatResult<Printed> {
+    tracing::trace_span!("format_node_to_writer").in_scope(move || {
+        let (printed, context) = format_root(root, language, |state, format_node| {
+            let mut buffer = StreamingBuffer::new(state, output);
+
+            write!(buffer, [format_node])?;
+
+            buffer.finish()
+        })?;
+
+        let printed = match context.source_map() {
+            Some(source_map) => source_map.map_printed(printed),
+            None => printed,
         };
 
-        let comments = Comments::from_node(&root, &L::CommentStyle::default(), source_map.as_ref());
-        let format_node = FormatRefWithRule::new(&root, L::FormatRule::default());
+        Ok(printed)
+    })
+}
+
+/// Formats `root` with `write_root`, which writes the passed [Format] of the root node to a
+/// buffer, and verifies that all the tokens and comments of the tree have been formatted.
+fn format_root<L: FormatLanguage, R>(
+    root: &SyntaxNode<L::SyntaxLanguage>,
+    language: L,
+    write_root: impl FnOnce(&mut FormatState<L::Context>, &dyn Format<L::Context>) -> FormatResult<R>,
+) -> FormatResult<(R, L::Context)> {
+    let (root, source_map) = match language.transform(root) {
+        Some((root, source_map)) => (root, Some(source_map)),
+        None => (root.clone(), None),
+    };
+
+    let comments = Comments::from_node(&root, &L::CommentStyle::default(), source_map.as_ref());
+    let format_node = FormatRefWithRule::new(&root, L::FormatRule::default());
 
-        let context = language.create_context(comments, source_map);
-        let mut state = FormatState::new(context);
-        let mut buffer = VecBuffer::new(&mut state);
+    let context = language.create_context(comments, source_map);
+    let mut state = FormatState::new(context);
 
-        write!(buffer, [format_node])?;
+    let result = write_root(&mut state, &format_node)?;
 
-        let mut document = Document::from(buffer.into_vec());
-        document.propagate_expand();
+    state.assert_formatted_all_tokens(&root);
 
-        state.assert_formatted_all_tokens(&root);
+    let context = state.into_context();
+    let comments = context.comments();
 
-        let context = state.into_context();
-        let comments = context.comments();
+    comments.assert_checked_all_suppressions(&root);
+    comments.collect_dropped_comments();
 
-        comments.assert_checked_all_suppressions(&root);
+    if context.is_strict_comments() {
         comments.assert_formatted_all_comments();
+    }
 
-        Ok(Formatted::new(document, context))
-    })
+    Ok((result, context))
 }
 
 /// Returns the [TextRange] for this [SyntaxElement] with the leading and
@@ -1433,6 +1647,9 @@ pub struct FormatState<Context> {
 
     group_id_builder: UniqueGroupIdBuilder,
 
+    /// The number of nodes that are currently being formatted
+    depth: u32,
+
     // This is using a RefCell as it only exists in debug mode,
     // the Formatter is still completely immutable in release builds
     #[cfg(debug_assertions)]
@@ -1456,6 +1673,7 @@ impl<Context> FormatState<Context> {
         Self {
             context,
             group_id_builder: Default::default(),
+            depth: 0,
 
             #[cfg(debug_assertions)]
             printed_tokens: Default::default(),
@@ -1483,6 +1701,31 @@ impl<Context> FormatState<Context> {
         self.group_id_builder.group_id(debug_name)
     }
 
+    /// Increments the nesting depth of the formatted nodes.
+    ///
+    /// Returns [FormatError::DepthExceeded] without changing the depth if the node
+    /// would be nested deeper than `max_depth`. Every successful call must be followed
+    /// by a call to [FormatState::exit_node] once the node is formatted.
+    pub fn enter_node(&mut self, max_depth: u32) -> FormatResult<()> {
+        if self.depth >= max_depth {
+            Err(FormatError::DepthExceeded)
+        } else {
+            self.depth += 1;
+            Ok(())
+        }
+    }
+
+    /// Decrements the nesting depth of the formatted nodes
+    pub fn exit_node(&mut self) {
+        debug_assert!(self.depth > 0, "Called `exit_node` without entering a node");
+        self.depth -= 1;
+    }
+
+    /// Returns the number of nodes that are currently being formatted
+    pub fn depth(&self) -> u32 {
+        self.depth
+    }
+
     /// Tracks the given token as formatted
     #[inline]
     pub fn track_token<L: Language>(&mut self, #[allow(unused_variables)] token: &SyntaxToken<L>) {
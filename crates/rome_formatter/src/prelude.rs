@@ -3,9 +3,11 @@ pub use crate::format_element::*;
 pub use crate::format_extensions::{FormatOptional as _, MemoizeFormat, Memoized};
 pub use crate::formatter::Formatter;
 pub use crate::printer::PrinterOptions;
+pub use crate::token::format_aligned_block_comment;
 pub use crate::trivia::{
-    format_dangling_comments, format_leading_comments, format_only_if_breaks, format_removed,
-    format_replaced, format_trailing_comments, format_trimmed_token,
+    format_dangling_comments, format_leading_comments, format_only_if_breaks, format_only_if_fits,
+    format_relocated_comments, format_removed, format_replaced, format_trailing_comments,
+    format_trailing_comments_before_delimiter, format_trimmed_token,
 };
 
 pub use crate::verbatim::{format_suppressed_node, format_unknown_node, format_verbatim_node};
@@ -56,16 +56,7 @@ impl<'a> Printer<'a> {
         indent: u16,
     ) -> PrintResult<Printed> {
         tracing::debug_span!("Printer::print").in_scope(move || {
-            let mut stack = PrintCallStack::new(PrintElementArgs::new(Indention::Level(indent)));
-            let mut queue: PrintQueue<'a> = PrintQueue::new(document.as_ref());
-
-            while let Some(element) = queue.pop() {
-                self.print_element(&mut stack, &mut queue, element)?;
-
-                if queue.is_empty() {
-                    self.flush_line_suffixes(&mut queue, &mut stack, None);
-                }
-            }
+            self.print_document(document, indent)?;
 
             Ok(Printed::new(
                 self.state.buffer,
@@ -76,6 +67,41 @@ impl<'a> Printer<'a> {
         })
     }
 
+    /// Prints the passed in element as the continuation of the chunks of the same document
+    /// printed before it with `chunks` and returns the code printed for this chunk.
+    ///
+    /// The printer resumes from the state left by the previous chunk, for example the elements
+    /// of this chunk can refer to the groups with an id printed in a previous chunk.
+    /// The source markers and verbatim ranges of the chunk are accumulated in `chunks`.
+    pub fn print_chunk(
+        mut self,
+        document: &'a Document,
+        chunks: &mut PrintedChunks,
+    ) -> PrintResult<String> {
+        tracing::debug_span!("Printer::print_chunk").in_scope(move || {
+            chunks.resume(&mut self.state);
+            let result = self.print_document(document, 0);
+            let code = chunks.suspend(&mut self.state);
+
+            result.map(|_| code)
+        })
+    }
+
+    fn print_document(&mut self, document: &'a Document, indent: u16) -> PrintResult<()> {
+        let mut stack = PrintCallStack::new(PrintElementArgs::new(Indention::Level(indent)));
+        let mut queue: PrintQueue<'a> = PrintQueue::new(document.as_ref());
+
+        while let Some(element) = queue.pop() {
+            self.print_element(&mut stack, &mut queue, element)?;
+
+            if queue.is_empty() {
+                self.flush_line_suffixes(&mut queue, &mut stack, None);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Prints a single element and push the following elements to queue
     fn print_element(
         &mut self,
@@ -119,11 +145,14 @@ impl<'a> Printer<'a> {
                         self.print_str("\n");
                     }
 
-                    // Print a second line break if this is an empty line
-                    if line_mode == &LineMode::Empty && !self.state.has_empty_line {
+                    // Print additional line breaks if this is an empty line, unless the
+                    // previous line breaks already printed the empty lines
+                    let mut empty_lines = self.state.empty_lines;
+                    while empty_lines < line_mode.empty_lines() {
                         self.print_str("\n");
-                        self.state.has_empty_line = true;
+                        empty_lines += 1;
                     }
+                    self.state.empty_lines = empty_lines;
 
                     self.state.pending_space = false;
                     self.state.pending_indent = args.indention();
@@ -148,7 +177,7 @@ impl<'a> Printer<'a> {
             }
 
             FormatElement::Tag(StartGroup(group)) => {
-                let group_mode = if !group.mode().is_flat() {
+                let group_mode = if self.options.is_group_expanded(group) {
                     PrintMode::Expanded
                 } else {
                     match args.mode() {
@@ -231,7 +260,7 @@ impl<'a> Printer<'a> {
                 stack.push(TagKind::IndentIfGroupBreaks, args);
             }
 
-            FormatElement::Tag(StartLineSuffix) => {
+            FormatElement::Tag(StartLineSuffix(_)) => {
                 self.state
                     .line_suffixes
                     .extend(args, queue.iter_content(TagKind::LineSuffix));
@@ -644,7 +673,7 @@ impl<'a> Printer<'a> {
         for char in content.chars() {
             self.print_char(char);
 
-            self.state.has_empty_line = false;
+            self.state.empty_lines = 0;
         }
     }
 
@@ -707,7 +736,8 @@ struct PrinterState<'a> {
     generated_line: usize,
     generated_column: usize,
     line_width: usize,
-    has_empty_line: bool,
+    /// The number of empty lines printed since the last non line break character
+    empty_lines: u8,
     line_suffixes: LineSuffixes<'a>,
     verbatim_markers: Vec<TextRange>,
     group_modes: GroupModes,
@@ -717,6 +747,80 @@ struct PrinterState<'a> {
     fits_queue: Vec<&'a [FormatElement]>,
 }
 
+/// State of a [Printer] carried over from one chunk of a document to the next by
+/// [Printer::print_chunk], along with the source markers and verbatim ranges of the printed chunks.
+#[derive(Debug, Default)]
+pub struct PrintedChunks {
+    /// Length of the code printed by the previous chunks
+    printed_len: TextSize,
+    source_markers: Vec<SourceMarker>,
+    source_position: TextSize,
+    pending_indent: Indention,
+    pending_space: bool,
+    measured_group_fits: bool,
+    generated_line: usize,
+    generated_column: usize,
+    line_width: usize,
+    empty_lines: u8,
+    verbatim_markers: Vec<TextRange>,
+    group_modes: GroupModes,
+}
+
+impl PrintedChunks {
+    /// Moves the state left by the previous chunk into the `state` of the printer of the next chunk
+    fn resume(&mut self, state: &mut PrinterState<'_>) {
+        state.source_position = self.source_position;
+        state.pending_indent = self.pending_indent;
+        state.pending_space = self.pending_space;
+        state.measured_group_fits = self.measured_group_fits;
+        state.generated_line = self.generated_line;
+        state.generated_column = self.generated_column;
+        state.line_width = self.line_width;
+        state.empty_lines = self.empty_lines;
+        state.group_modes = std::mem::take(&mut self.group_modes);
+    }
+
+    /// Moves the state of the printer back after printing a chunk and returns the code of the chunk.
+    /// The positions of the markers and ranges in the code of the chunk are offset by the length of
+    /// the previous chunks.
+    fn suspend(&mut self, state: &mut PrinterState<'_>) -> String {
+        let code = std::mem::take(&mut state.buffer);
+        let offset = self.printed_len;
+
+        self.source_markers
+            .extend(state.source_markers.drain(..).map(|marker| SourceMarker {
+                source: marker.source,
+                dest: marker.dest + offset,
+            }));
+        self.verbatim_markers
+            .extend(state.verbatim_markers.drain(..).map(|range| range + offset));
+
+        self.printed_len += code.text_len();
+        self.source_position = state.source_position;
+        self.pending_indent = state.pending_indent;
+        self.pending_space = state.pending_space;
+        self.measured_group_fits = state.measured_group_fits;
+        self.generated_line = state.generated_line;
+        self.generated_column = state.generated_column;
+        self.line_width = state.line_width;
+        self.empty_lines = state.empty_lines;
+        self.group_modes = std::mem::take(&mut state.group_modes);
+
+        code
+    }
+
+    /// Returns the source markers and verbatim ranges of all the printed chunks.
+    /// The code of the result is empty, the code of the chunks is returned by [Printer::print_chunk].
+    pub fn finish(self) -> Printed {
+        Printed::new(
+            String::new(),
+            None,
+            self.source_markers,
+            self.verbatim_markers,
+        )
+    }
+}
+
 /// Tracks the mode in which groups with ids are printed. Stores the groups at `group.id()` index.
 /// This is based on the assumption that the group ids for a single document are dense.
 #[derive(Debug, Default)]
@@ -982,7 +1086,7 @@ impl<'a, 'print> FitsMeasurer<'a, 'print> {
                             self.state.pending_space = true;
                         }
                         LineMode::Soft => {}
-                        LineMode::Hard | LineMode::Empty => {
+                        LineMode::Hard | LineMode::Empty | LineMode::EmptyLines(_) => {
                             return Ok(if self.must_be_flat {
                                 Fits::No
                             } else {
@@ -1010,7 +1114,7 @@ impl<'a, 'print> FitsMeasurer<'a, 'print> {
             }
 
             FormatElement::ExpandParent => {
-                if self.must_be_flat {
+                if self.must_be_flat && !self.options().measurement_mode {
                     return Ok(Fits::No);
                 }
             }
@@ -1051,11 +1155,13 @@ impl<'a, 'print> FitsMeasurer<'a, 'print> {
             }
 
             FormatElement::Tag(StartGroup(group)) => {
-                if self.must_be_flat && !group.mode().is_flat() {
+                let is_expanded = self.options().is_group_expanded(group);
+
+                if self.must_be_flat && is_expanded {
                     return Ok(Fits::No);
                 }
 
-                let group_mode = if !group.mode().is_flat() {
+                let group_mode = if is_expanded {
                     PrintMode::Expanded
                 } else {
                     args.mode()
@@ -1104,9 +1210,17 @@ impl<'a, 'print> FitsMeasurer<'a, 'print> {
                 }
             }
 
-            FormatElement::Tag(StartLineSuffix) => {
+            FormatElement::Tag(StartLineSuffix(reserved_width)) => {
                 self.queue.skip_content(TagKind::LineSuffix);
                 self.state.has_line_suffix = true;
+
+                if *reserved_width > 0 {
+                    self.state.line_width += *reserved_width as usize;
+
+                    if self.state.line_width > self.options().print_width.into() {
+                        return Ok(Fits::No);
+                    }
+                }
             }
 
             FormatElement::Tag(EndLineSuffix) => {
@@ -1260,7 +1374,10 @@ struct FitsState {
 mod tests {
     use crate::prelude::*;
     use crate::printer::{LineEnding, PrintWidth, Printer, PrinterOptions};
-    use crate::{format_args, write, Document, FormatState, IndentStyle, Printed, VecBuffer};
+    use crate::{
+        format_args, write, Document, FormatError, FormatState, IndentStyle, Printed,
+        StreamingBuffer, VecBuffer,
+    };
 
     fn format(root: &dyn Format<SimpleFormatContext>) -> Printed {
         format_with_options(
@@ -1554,6 +1671,49 @@ two lines`,
         assert_eq!(printed.as_code(), "[1, 2, 3]; // trailing")
     }
 
+    #[test]
+    fn line_suffix_reserved_width() {
+        let footnote = |comment: &'static str| {
+            format_with(move |f| {
+                let trailing = format_with(|f| write!(f, [space(), text(comment)]));
+                write!(
+                    f,
+                    [group(&format_args![
+                        if_group_fits_on_line(
+                            &line_suffix(&trailing).with_reserved_width(1 + comment.len() as u32)
+                        ),
+                        if_group_breaks(&line_suffix(&indent(&format_args![
+                            soft_line_break(),
+                            text(comment)
+                        ])))
+                    ])]
+                )
+            })
+        };
+
+        let printed = format_with_options(
+            &format_args![
+                text("a"),
+                footnote("// short"),
+                text(";"),
+                hard_line_break(),
+                text("b"),
+                footnote("// a comment exceeding the line width"),
+                text(";")
+            ],
+            PrinterOptions {
+                print_width: PrintWidth::new(20),
+                indent_style: IndentStyle::Space(2),
+                ..PrinterOptions::default()
+            },
+        );
+
+        assert_eq!(
+            printed.as_code(),
+            "a; // short\nb;\n  // a comment exceeding the line width"
+        )
+    }
+
     #[test]
     fn conditional_with_group_id_in_fits() {
         let content = format_with(|f| {
@@ -1624,6 +1784,98 @@ Group 1 breaks"#
         );
     }
 
+    #[test]
+    fn measurement_mode_ignores_expand_parent() {
+        let content = format_with(|f| {
+            write!(
+                f,
+                [
+                    group(&format_args![
+                        text("["),
+                        soft_block_indent(&format_args![
+                            text("a"),
+                            line_suffix(&format_args![space(), text("// comment")]),
+                            expand_parent()
+                        ]),
+                        text("]")
+                    ]),
+                    hard_line_break(),
+                    group(&format_args![
+                        text("["),
+                        soft_block_indent(&format_args![text("a"), hard_line_break(), text("b")]),
+                        text("]")
+                    ])
+                ]
+            )
+        });
+
+        let printed = format(&content);
+        assert_eq!(printed.as_code(), "[\n  a // comment\n]\n[\n  a\n  b\n]");
+
+        let printed = format_with_options(
+            &content,
+            PrinterOptions {
+                indent_style: IndentStyle::Space(2),
+                measurement_mode: true,
+                ..PrinterOptions::default()
+            },
+        );
+        assert_eq!(printed.as_code(), "[a] // comment\n[\n  a\n  b\n]");
+    }
+
+    #[test]
+    fn streaming_buffer_keeps_group_modes_across_flushes() {
+        let mut state = FormatState::new(SimpleFormatContext::default());
+        let group_id = state.group_id("group");
+        let mut output = String::new();
+
+        let mut buffer = StreamingBuffer::new(&mut state, &mut output);
+
+        write!(
+            buffer,
+            [
+                group(&format_args![text("a"), hard_line_break(), text("b")])
+                    .with_group_id(Some(group_id))
+            ]
+        )
+        .unwrap();
+        buffer.flush().unwrap();
+
+        write!(
+            buffer,
+            [
+                hard_line_break(),
+                if_group_breaks(&text("breaks")).with_group_id(Some(group_id)),
+                if_group_fits_on_line(&text("fits")).with_group_id(Some(group_id))
+            ]
+        )
+        .unwrap();
+
+        let printed = buffer.finish().unwrap();
+
+        assert_eq!(printed.as_code(), "");
+        assert_eq!(output, "a\nb\nbreaks");
+    }
+
+    #[test]
+    fn streaming_buffer_rejects_restoring_a_flushed_snapshot() {
+        let mut state = FormatState::new(SimpleFormatContext::default());
+        let mut output = String::new();
+
+        let mut buffer = StreamingBuffer::new(&mut state, &mut output);
+
+        let snapshot = buffer.snapshot();
+        write!(buffer, [text("a")]).unwrap();
+        buffer.flush().unwrap();
+
+        buffer.restore_snapshot(snapshot);
+
+        assert_eq!(
+            write!(buffer, [text("b")]),
+            Err(FormatError::FlushedSnapshot)
+        );
+    }
+
     struct FormatArrayElements<'a> {
         items: Vec<&'a dyn Format<SimpleFormatContext>>,
     }
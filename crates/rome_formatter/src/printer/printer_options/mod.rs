@@ -1,3 +1,4 @@
+use crate::format_element::tag::{Group, GroupMode};
 use crate::{IndentStyle, LineWidth};
 
 /// Options that affect how the [crate::Printer] prints the format tokens
@@ -14,6 +15,12 @@ pub struct PrinterOptions {
 
     /// Whether the printer should use tabs or spaces to indent code and if spaces, by how many.
     pub indent_style: IndentStyle,
+
+    /// Whether the printer should treat [expand_parent](crate::builders::expand_parent) as a no-op.
+    ///
+    /// Useful to measure if some content fits regardless of the trailing line comments that force
+    /// their enclosing groups to expand. Groups containing a hard line break still expand.
+    pub measurement_mode: bool,
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -55,10 +62,25 @@ impl PrinterOptions {
         self
     }
 
+    pub fn with_measurement_mode(mut self, measurement_mode: bool) -> Self {
+        self.measurement_mode = measurement_mode;
+
+        self
+    }
+
     pub(crate) fn indent_style(&self) -> IndentStyle {
         self.indent_style
     }
 
+    /// Returns `true` if the printer must print `group` in expanded mode.
+    pub(crate) fn is_group_expanded(&self, group: &Group) -> bool {
+        match group.mode() {
+            GroupMode::Flat => false,
+            GroupMode::PropagatedFromExpandParent => !self.measurement_mode,
+            GroupMode::Expand | GroupMode::Propagated => true,
+        }
+    }
+
     /// Width of an indent in characters.
     pub(super) const fn indent_width(&self) -> u8 {
         match self.indent_style {
@@ -99,6 +121,7 @@ impl Default for PrinterOptions {
             print_width: PrintWidth::default(),
             indent_style: Default::default(),
             line_ending: LineEnding::LineFeed,
+            measurement_mode: false,
         }
     }
 }
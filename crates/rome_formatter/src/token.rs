@@ -0,0 +1,89 @@
+//! Provides builders that the comment rules of the languages can opt into to format the text of
+//! their comment tokens.
+
+use crate::prelude::*;
+use crate::{write, CstFormatContext, SourceComment};
+use rome_rowan::{Language, TextLen};
+
+/// Formats `comment`, re-indenting the lines of a multiline [block](crate::comments::CommentKind::is_block)
+/// comment that all start with a `*` so that each `*` is aligned one space after the first
+/// character of the opening delimiter:
+///
+/// ```javascript
+/// /**
+///  * Doc comment
+///  */
+/// ```
+///
+/// Any other comment, for example a block comment containing ASCII art or commented out code,
+/// is written exactly as in the source. The opening delimiter and the first line of the comment
+/// are always written as a single text element.
+///
+/// This is meant to be called from the comment rule of a language, which formats the leading,
+/// trailing, and dangling comments alike.
+pub const fn format_aligned_block_comment<L: Language>(
+    comment: &SourceComment<L>,
+) -> FormatAlignedBlockComment<L> {
+    FormatAlignedBlockComment { comment }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct FormatAlignedBlockComment<'a, L: Language> {
+    comment: &'a SourceComment<L>,
+}
+
+impl<L: Language, C> Format<C> for FormatAlignedBlockComment<'_, L>
+where
+    C: CstFormatContext<Language = L>,
+{
+    fn fmt(&self, f: &mut Formatter<C>) -> FormatResult<()> {
+        let piece = self.comment.piece();
+
+        if !is_aligned_block_comment(self.comment) {
+            return write!(f, [piece.as_piece()]);
+        }
+
+        let mut source_offset = piece.text_range().start();
+        let mut lines = piece.text().lines();
+
+        // SAFETY: Safe, `is_aligned_block_comment` only returns `true` for multiline comments
+        let first_line = lines.next().unwrap();
+        write!(f, [dynamic_text(first_line.trim_end(), source_offset)])?;
+
+        source_offset += first_line.text_len();
+
+        // Indent the remaining lines by one space so that all `*` are aligned.
+        write!(
+            f,
+            [align(
+                1,
+                &format_once(|f| {
+                    for line in lines {
+                        write!(
+                            f,
+                            [hard_line_break(), dynamic_text(line.trim(), source_offset)]
+                        )?;
+
+                        source_offset += line.text_len();
+                    }
+
+                    Ok(())
+                })
+            )]
+        )
+    }
+}
+
+/// Returns `true` if `comment` is a multiline block comment whose lines following the first line
+/// all start with a `*`, ignoring their indentation.
+pub fn is_aligned_block_comment<L: Language>(comment: &SourceComment<L>) -> bool {
+    let piece = comment.piece();
+
+    comment.kind().is_block()
+        && piece.has_newline()
+        && piece
+            .text()
+            .lines()
+            .skip(1)
+            .all(|line| line.trim_start().starts_with('*'))
+}
@@ -4,12 +4,12 @@ use crate::format_element::tag::VerbatimKind;
 use crate::prelude::*;
 use crate::{
     comments::{CommentKind, CommentStyle},
-    write, Argument, Arguments, CstFormatContext, FormatRefWithRule, GroupId, SourceComment,
-    TextRange,
+    format_args, write, Argument, Arguments, CstFormatContext, FormatRefWithRule, GroupId,
+    SourceComment, TextRange, TextSize,
 };
-use rome_rowan::{Language, SyntaxNode, SyntaxToken};
-#[cfg(debug_assertions)]
+use rome_rowan::{Language, SyntaxNode, SyntaxToken, SyntaxTriviaPieceComments, TextLen};
 use std::cell::Cell;
+use unicode_width::UnicodeWidthStr;
 
 /// Formats the leading comments of `node`
 pub const fn format_leading_comments<L: Language>(
@@ -19,6 +19,12 @@ pub const fn format_leading_comments<L: Language>(
 }
 
 /// Formats the leading comments of a node.
+///
+/// The text immediately following the opening delimiter of a block comment stays on the same line
+/// as the delimiter: `/* first\n second */` never prints `/*` and `first` on separate lines.
+/// The verbatim comments are written line by line, and the comment rules of the languages write
+/// the delimiter and the first line as a single text element, see
+/// [format_aligned_block_comment](crate::token::format_aligned_block_comment).
 #[derive(Debug, Copy, Clone)]
 pub enum FormatLeadingComments<'a, L: Language> {
     Node(&'a SyntaxNode<L>),
@@ -31,6 +37,7 @@ where
 {
     fn fmt(&self, f: &mut Formatter<Context>) -> FormatResult<()> {
         let comments = f.context().comments().clone();
+        let max_empty_lines = f.context().max_empty_lines();
 
         let leading_comments = match self {
             FormatLeadingComments::Node(node) => comments.leading_comments(node),
@@ -38,11 +45,29 @@ where
         };
 
         for comment in leading_comments {
-            let format_comment = FormatRefWithRule::new(comment, Context::CommentRule::default());
-            write!(f, [format_comment])?;
+            if comments.is_consumed_by_verbatim(comment) {
+                comment.mark_formatted();
+                continue;
+            }
+
+            // Legal comments must survive minifiers, keep them on their own line
+            if Context::Style::is_legal_comment(comment.piece()) {
+                write!(
+                    f,
+                    [
+                        FormatComment(comment),
+                        source_empty_lines(comment.lines_after().max(1), max_empty_lines)
+                    ]
+                )?;
+
+                comment.mark_formatted();
+                continue;
+            }
 
             match comment.kind() {
                 CommentKind::Block | CommentKind::InlineBlock => {
+                    write!(f, [FormatComment(comment)])?;
+
                     match comment.lines_after() {
                         0 => write!(f, [space()])?,
                         1 => {
@@ -52,13 +77,16 @@ where
                                 write!(f, [hard_line_break()])?;
                             }
                         }
-                        _ => write!(f, [empty_line()])?,
+                        lines => write!(f, [source_empty_lines(lines, max_empty_lines)])?,
                     };
                 }
-                CommentKind::Line => match comment.lines_after() {
-                    0 | 1 => write!(f, [hard_line_break()])?,
-                    _ => write!(f, [empty_line()])?,
-                },
+                CommentKind::Line => write!(
+                    f,
+                    [
+                        FormatComment(comment),
+                        source_empty_lines(comment.lines_after(), max_empty_lines)
+                    ]
+                )?,
             }
 
             comment.mark_formatted()
@@ -68,18 +96,271 @@ where
     }
 }
 
+/// Formats `comments` that have been moved away from their position in the source, for example
+/// by a rule hoisting the comments of a node to another node.
+///
+/// The lines stored on the comments are relative to their original position, and the builder uses
+/// them for the gaps between the comments only. The gap before the first comment and after the last
+/// comment can be overridden with [FormatRelocatedComments::with_leading_lines] and
+/// [FormatRelocatedComments::with_trailing_lines].
+pub const fn format_relocated_comments<L: Language>(
+    comments: &[SourceComment<L>],
+) -> FormatRelocatedComments<L> {
+    FormatRelocatedComments {
+        comments,
+        leading_lines: None,
+        trailing_lines: None,
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct FormatRelocatedComments<'a, L: Language> {
+    comments: &'a [SourceComment<L>],
+    leading_lines: Option<u32>,
+    trailing_lines: Option<u32>,
+}
+
+impl<L: Language> FormatRelocatedComments<'_, L> {
+    /// Sets the number of line breaks between the preceding content and the first comment,
+    /// instead of the [lines before](SourceComment::lines_before) the comment in the source.
+    ///
+    /// `0` keeps the comment on the line of the preceding content, `1` moves it on the next line,
+    /// and `2` separates it by an empty line.
+    pub fn with_leading_lines(mut self, lines: u32) -> Self {
+        self.leading_lines = Some(lines);
+        self
+    }
+
+    /// Sets the number of line breaks between the last comment and the following content,
+    /// instead of the [lines after](SourceComment::lines_after) the comment in the source.
+    ///
+    /// A line comment is always followed by a line break.
+    pub fn with_trailing_lines(mut self, lines: u32) -> Self {
+        self.trailing_lines = Some(lines);
+        self
+    }
+}
+
+impl<Context> Format<Context> for FormatRelocatedComments<'_, Context::Language>
+where
+    Context: CstFormatContext,
+{
+    fn fmt(&self, f: &mut Formatter<Context>) -> FormatResult<()> {
+        let max_empty_lines = f.context().max_empty_lines();
+
+        let write_lines = |lines: u32, f: &mut Formatter<Context>| match lines {
+            0 => write!(f, [space()]),
+            lines => write!(f, [source_empty_lines(lines, max_empty_lines)]),
+        };
+
+        for (index, comment) in self.comments.iter().enumerate() {
+            let lines_before = match self.leading_lines {
+                Some(lines) if index == 0 => lines,
+                _ => comment.lines_before(),
+            };
+
+            write_lines(lines_before, f)?;
+            write!(f, [FormatComment(comment)])?;
+
+            comment.mark_formatted();
+        }
+
+        if let Some(last) = self.comments.last() {
+            let lines_after = self.trailing_lines.unwrap_or_else(|| last.lines_after());
+
+            if last.kind().is_line() {
+                write_lines(lines_after.max(1), f)?;
+            } else {
+                write_lines(lines_after, f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats a comment with the comment rule of the language, or verbatim if it is a
+/// [framed block](CommentStyle::is_framed_block) whose alignment must be preserved or
+/// a [directive](CommentStyle::is_directive).
+///
+/// The lines following the first line of a verbatim multiline comment are re-indented by the
+/// same amount as the first line so that the lines stay aligned with each other.
+struct FormatComment<'a, L: Language>(&'a SourceComment<L>);
+
+impl<Context> Format<Context> for FormatComment<'_, Context::Language>
+where
+    Context: CstFormatContext,
+{
+    fn fmt(&self, f: &mut Formatter<Context>) -> FormatResult<()> {
+        let comment = self.0;
+
+        if Context::Style::is_framed_block(comment.piece())
+            || Context::Style::is_directive(comment.piece())
+        {
+            write!(f, [FormatVerbatimComment(comment.piece())])
+        } else {
+            write!(
+                f,
+                [FormatRefWithRule::new(
+                    comment,
+                    Context::CommentRule::default()
+                )]
+            )
+        }
+    }
+}
+
+/// Writes a comment as in the source, except that the indentation of the first line in the source
+/// is removed from the lines following it. The printer then indents all lines to the current
+/// indentation level.
+struct FormatVerbatimComment<'a, L: Language>(&'a SyntaxTriviaPieceComments<L>);
+
+impl<L: Language, C> Format<C> for FormatVerbatimComment<'_, L>
+where
+    C: CstFormatContext<Language = L>,
+{
+    fn fmt(&self, f: &mut Formatter<C>) -> FormatResult<()> {
+        let comment = self.0;
+
+        let indent_width = match source_indent_width(comment) {
+            Some(width) if comment.has_newline() => width,
+            _ => return write!(f, [comment.as_piece()]),
+        };
+
+        let mut source_offset = comment.text_range().start();
+        let mut empty_lines_before = None;
+
+        for line in comment.text().split_inclusive('\n') {
+            let line_start = source_offset;
+            source_offset += line.text_len();
+
+            let indent = line
+                .char_indices()
+                .take(indent_width)
+                .find(|(_, c)| !matches!(c, ' ' | '\t'))
+                // All skipped characters are ASCII whitespace, one byte each
+                .map_or_else(|| indent_width.min(line.len()), |(index, _)| index);
+            let line = line[indent..].trim_end();
+
+            if line.is_empty() {
+                if let Some(count) = empty_lines_before.as_mut() {
+                    *count += 1;
+                }
+                continue;
+            }
+
+            if let Some(count) = empty_lines_before {
+                write!(f, [empty_lines(count)])?;
+            }
+
+            write!(
+                f,
+                [dynamic_text(
+                    line,
+                    line_start + TextSize::from(indent as u32)
+                )]
+            )?;
+
+            empty_lines_before = Some(0);
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the number of whitespace characters preceding `comment` on its line in the source
+/// or `None` if `comment` doesn't start its line.
+fn source_indent_width<L: Language>(comment: &SyntaxTriviaPieceComments<L>) -> Option<usize> {
+    let token = comment.as_piece().token();
+    let start = comment.text_range().start();
+
+    if token.text_trimmed_range().end() <= start {
+        // A trailing comment is preceded by the token on the same line
+        return None;
+    }
+
+    let mut width = 0;
+
+    for piece in token
+        .leading_trivia()
+        .pieces()
+        .take_while(|piece| piece.text_range().end() <= start)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+    {
+        if piece.is_newline() {
+            return Some(width);
+        } else if piece.is_whitespace() {
+            width += piece.text().chars().count();
+        } else {
+            return None;
+        }
+    }
+
+    // The comment is at the start of the file
+    token.prev_token().is_none().then_some(width)
+}
+
+/// Returns the line break separating two elements that are `lines` line breaks apart in the source,
+/// preserving at most `max_empty_lines` of the empty lines between them.
+fn source_empty_lines(lines: u32, max_empty_lines: u8) -> Line {
+    let count = lines.saturating_sub(1).min(u32::from(max_empty_lines));
+
+    // SAFETY: The count is at most `max_empty_lines`, a `u8`
+    empty_lines(count as u8)
+}
+
 /// Formats the trailing comments of `node`.
 pub const fn format_trailing_comments<L: Language>(
     node: &SyntaxNode<L>,
 ) -> FormatTrailingComments<L> {
-    FormatTrailingComments::Node(node)
+    FormatTrailingComments::Node {
+        node,
+        footnotes: false,
+    }
 }
 
 /// Formats the trailing comments of `node`
 #[derive(Debug, Clone, Copy)]
 pub enum FormatTrailingComments<'a, L: Language> {
-    Node(&'a SyntaxNode<L>),
-    Comments(&'a [SourceComment<L>]),
+    Node {
+        node: &'a SyntaxNode<L>,
+        footnotes: bool,
+    },
+    Comments {
+        comments: &'a [SourceComment<L>],
+        footnotes: bool,
+    },
+}
+
+impl<'a, L: Language> FormatTrailingComments<'a, L> {
+    /// Formats the trailing `comments`.
+    pub const fn comments(comments: &'a [SourceComment<L>]) -> Self {
+        FormatTrailingComments::Comments {
+            comments,
+            footnotes: false,
+        }
+    }
+
+    /// Moves the trailing line comments that exceed the line width on their own line, indented
+    /// under the line they trail:
+    ///
+    /// ```javascript
+    /// const a = call(argument); // short
+    /// const b = call(argument);
+    ///     // a comment that would exceed the line width if it trailed the statement
+    /// ```
+    ///
+    /// The placement is chosen per comment, by measuring the remaining width of the line.
+    /// Block comments and comments on their own line are formatted as usual.
+    pub fn with_footnotes(mut self) -> Self {
+        match &mut self {
+            FormatTrailingComments::Node { footnotes, .. } => *footnotes = true,
+            FormatTrailingComments::Comments { footnotes, .. } => *footnotes = true,
+        }
+        self
+    }
 }
 
 impl<Context> Format<Context> for FormatTrailingComments<'_, Context::Language>
@@ -88,17 +369,63 @@ where
 {
     fn fmt(&self, f: &mut Formatter<Context>) -> FormatResult<()> {
         let comments = f.context().comments().clone();
-        let trailing_comments = match self {
-            FormatTrailingComments::Node(node) => comments.trailing_comments(node),
-            FormatTrailingComments::Comments(comments) => comments,
+        let (trailing_comments, footnotes, node) = match self {
+            FormatTrailingComments::Node { node, footnotes } => {
+                (comments.trailing_comments(node), *footnotes, Some(*node))
+            }
+            FormatTrailingComments::Comments {
+                comments,
+                footnotes,
+            } => (*comments, *footnotes, None),
         };
+        let max_empty_lines = f.context().max_empty_lines();
+        let leading_spaces = f.context().trailing_comment_leading_spaces();
 
         let mut total_lines_before = 0;
 
         for comment in trailing_comments {
+            if comments.is_consumed_by_verbatim(comment) {
+                comment.mark_formatted();
+                continue;
+            }
+
             total_lines_before += comment.lines_before();
 
-            let format_comment = FormatRefWithRule::new(comment, Context::CommentRule::default());
+            let format_comment = FormatComment(comment);
+
+            // Only pad the comments ending their line, a block comment followed by code like
+            // `a /* comment */, b` stays separated by a single space
+            let leading_spaces = if comment.kind().is_line() || comment.lines_after() > 0 {
+                leading_spaces
+            } else {
+                leading_spaces.min(1)
+            };
+
+            // A directive applies to the code around it. Moving it to the end of the line with a
+            // line suffix could move it after code that follows it in the source, but it must still
+            // be printed after the tokens separating it from the node, like the `=` in `a = // @ts-ignore`
+            if Context::Style::is_directive(comment.piece())
+                && node.map_or(true, |node| follows_last_token(node, comment))
+            {
+                if total_lines_before > 0 {
+                    write!(
+                        f,
+                        [
+                            source_empty_lines(comment.lines_before(), max_empty_lines),
+                            format_comment
+                        ]
+                    )?;
+                } else {
+                    write!(f, [spaces(leading_spaces), format_comment])?;
+                }
+
+                if comment.kind().is_line() || total_lines_before > 0 {
+                    write!(f, [hard_line_break()])?;
+                }
+
+                comment.mark_formatted();
+                continue;
+            }
 
             // This allows comments at the end of nested structures:
             // {
@@ -116,20 +443,49 @@ where
                     f,
                     [
                         line_suffix(&format_with(|f| {
-                            match comment.lines_before() {
-                                0 | 1 => write!(f, [hard_line_break()])?,
-                                _ => write!(f, [empty_line()])?,
-                            };
-
-                            write!(f, [format_comment])
+                            write!(
+                                f,
+                                [
+                                    source_empty_lines(comment.lines_before(), max_empty_lines),
+                                    format_comment
+                                ]
+                            )
                         })),
                         expand_parent()
                     ]
                 )?;
             } else {
-                let content = format_with(|f| write!(f, [space(), format_comment]));
-                if comment.kind().is_line() {
+                let content = format_with(|f| write!(f, [spaces(leading_spaces), format_comment]));
+                if comment.kind().is_line() && footnotes {
+                    // Reserve the width of the comment so that the group only fits if
+                    // the comment fits on the line it trails
+                    let reserved_width =
+                        leading_spaces as u32 + comment.piece().text().width() as u32;
+                    let footnote = format_with(|f| {
+                        write!(
+                            f,
+                            [indent(&format_args![soft_line_break(), format_comment])]
+                        )
+                    });
+
+                    write!(
+                        f,
+                        [
+                            group(&format_args![
+                                if_group_fits_on_line(
+                                    &line_suffix(&content).with_reserved_width(reserved_width)
+                                ),
+                                if_group_breaks(&line_suffix(&footnote))
+                            ]),
+                            expand_parent()
+                        ]
+                    )?;
+                } else if comment.kind().is_line() {
                     write!(f, [line_suffix(&content), expand_parent()])?;
+                } else if comment.kind().is_block() {
+                    // The line breaks of a multiline block comment are part of its text. Expand the
+                    // enclosing group because its content doesn't fit on a single line
+                    write!(f, [content, expand_parent()])?;
                 } else {
                     write!(f, [content])?;
                 }
@@ -142,6 +498,82 @@ where
     }
 }
 
+/// Returns `true` if no token separates `comment` from the last token of `node` in the source.
+fn follows_last_token<L: Language>(node: &SyntaxNode<L>, comment: &SourceComment<L>) -> bool {
+    let piece = comment.piece();
+    let token = piece.as_piece().token();
+
+    let preceding_token = if token.text_trimmed_range().end() <= piece.text_range().start() {
+        // Trailing trivia of the token
+        Some(token)
+    } else {
+        token.prev_token()
+    };
+
+    preceding_token == node.last_token()
+}
+
+/// Formats the trailing comments of `node` when it is the last child before a closing delimiter,
+/// for example, the last element of an array or the last statement of a block.
+///
+/// Forces the delimiter onto a new line if any of the trailing comments is a line comment or
+/// a comment on its own line, regardless of whether the enclosing groups expand:
+///
+/// ```javascript
+/// [
+///     a // comment
+/// ]
+/// ```
+///
+/// The builder must be the last element of the delimited content so that the printer prints
+/// the delimiter on the line following the comments. It only formats the comments that the
+/// rule of `node` hasn't formatted yet.
+pub const fn format_trailing_comments_before_delimiter<L: Language>(
+    node: &SyntaxNode<L>,
+) -> FormatTrailingCommentsBeforeDelimiter<L> {
+    FormatTrailingCommentsBeforeDelimiter { node }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct FormatTrailingCommentsBeforeDelimiter<'a, L: Language> {
+    node: &'a SyntaxNode<L>,
+}
+
+impl<Context> Format<Context> for FormatTrailingCommentsBeforeDelimiter<'_, Context::Language>
+where
+    Context: CstFormatContext,
+{
+    fn fmt(&self, f: &mut Formatter<Context>) -> FormatResult<()> {
+        let comments = f.context().comments().clone();
+        let trailing_comments = comments.trailing_comments(self.node);
+
+        // Mirrors [FormatTrailingComments]: line comments and comments following a comment
+        // on its own line are printed as line suffixes that must be followed by a line break
+        let mut total_lines_before = 0;
+        let has_line_suffix = trailing_comments.iter().any(|comment| {
+            if comments.is_consumed_by_verbatim(comment) {
+                return false;
+            }
+
+            total_lines_before += comment.lines_before();
+            total_lines_before > 0 || comment.kind().is_line()
+        });
+
+        let unformatted = trailing_comments
+            .iter()
+            .position(|comment| !comment.formatted.get())
+            .map_or(&[][..], |start| &trailing_comments[start..]);
+
+        write!(f, [FormatTrailingComments::comments(unformatted)])?;
+
+        if has_line_suffix {
+            write!(f, [hard_line_break()])?;
+        }
+
+        Ok(())
+    }
+}
+
 /// Formats the dangling comments of `node`.
 pub const fn format_dangling_comments<L: Language>(
     node: &SyntaxNode<L>,
@@ -149,6 +581,9 @@ pub const fn format_dangling_comments<L: Language>(
     FormatDanglingComments::Node {
         node,
         indent: DanglingIndentMode::None,
+        preserve_gaps: false,
+        soft: false,
+        single_block: false,
     }
 }
 
@@ -157,10 +592,16 @@ pub enum FormatDanglingComments<'a, L: Language> {
     Node {
         node: &'a SyntaxNode<L>,
         indent: DanglingIndentMode,
+        preserve_gaps: bool,
+        soft: bool,
+        single_block: bool,
     },
     Comments {
         comments: &'a [SourceComment<L>],
         indent: DanglingIndentMode,
+        preserve_gaps: bool,
+        soft: bool,
+        single_block: bool,
     },
 }
 
@@ -184,6 +625,8 @@ pub enum DanglingIndentMode {
     /// Writes every comment on its own line and indents them with a soft line indent.
     /// Guarantees to write a line break if the last formatted comment is a [line](CommentKind::Line) comment.
     ///
+    /// Behaves like [DanglingIndentMode::Block] if [CstFormatContext::dangling_comments_force_break] is `true`.
+    ///
     /// # Examples
     ///
     /// ```ignore
@@ -201,6 +644,8 @@ pub enum DanglingIndentMode {
     Soft,
 
     /// Writes every comment on its own line.
+    ///
+    /// Expands the enclosing group if [CstFormatContext::dangling_comments_force_break] is `true`.
     None,
 }
 
@@ -215,6 +660,65 @@ impl<L: Language> FormatDanglingComments<'_, L> {
         self.with_indent_mode(DanglingIndentMode::Soft)
     }
 
+    /// Separates two comments by an empty line if there's at least one empty line between them
+    /// in the source, the same as leading comments do with the code following them. Comments are
+    /// separated by a single line break otherwise.
+    ///
+    /// ```ignore
+    /// {
+    ///     /* first */
+    ///
+    ///     /* second */
+    ///     /* third */
+    /// }
+    /// ```
+    pub fn preserve_gaps(mut self) -> Self {
+        match &mut self {
+            FormatDanglingComments::Node { preserve_gaps, .. } => *preserve_gaps = true,
+            FormatDanglingComments::Comments { preserve_gaps, .. } => *preserve_gaps = true,
+        }
+        self
+    }
+
+    /// Separates the comments by a space and keeps them on the same line as the surrounding
+    /// content if they fit, for example in a [block](DanglingIndentMode::Block) indent:
+    ///
+    /// ```ignore
+    /// { /* todo */ }
+    ///
+    /// {
+    ///     /* a comment that doesn't fit on the line */
+    ///     /* other */
+    /// }
+    /// ```
+    ///
+    /// Has no effect if any comment is a [line](CommentKind::Line) comment or if
+    /// [CstFormatContext::dangling_comments_force_break] is `true`.
+    pub fn soft(mut self) -> Self {
+        match &mut self {
+            FormatDanglingComments::Node { soft, .. } => *soft = true,
+            FormatDanglingComments::Comments { soft, .. } => *soft = true,
+        }
+        self
+    }
+
+    /// Keeps a single inline block comment on the line of the opening delimiter if it fits,
+    /// the same as [soft](FormatDanglingComments::soft) does for any number of comments:
+    ///
+    /// ```ignore
+    /// function f() { /* noop */ }
+    /// ```
+    ///
+    /// Multiple comments, line comments, and comments that don't start on the line of the
+    /// opening delimiter are formatted with the indent mode.
+    pub fn group_if_single_block(mut self) -> Self {
+        match &mut self {
+            FormatDanglingComments::Node { single_block, .. } => *single_block = true,
+            FormatDanglingComments::Comments { single_block, .. } => *single_block = true,
+        }
+        self
+    }
+
     fn with_indent_mode(mut self, mode: DanglingIndentMode) -> Self {
         match &mut self {
             FormatDanglingComments::Node { indent, .. } => *indent = mode,
@@ -229,6 +733,27 @@ impl<L: Language> FormatDanglingComments<'_, L> {
             FormatDanglingComments::Comments { indent, .. } => *indent,
         }
     }
+
+    const fn has_preserve_gaps(&self) -> bool {
+        match self {
+            FormatDanglingComments::Node { preserve_gaps, .. } => *preserve_gaps,
+            FormatDanglingComments::Comments { preserve_gaps, .. } => *preserve_gaps,
+        }
+    }
+
+    const fn is_soft(&self) -> bool {
+        match self {
+            FormatDanglingComments::Node { soft, .. } => *soft,
+            FormatDanglingComments::Comments { soft, .. } => *soft,
+        }
+    }
+
+    const fn is_group_if_single_block(&self) -> bool {
+        match self {
+            FormatDanglingComments::Node { single_block, .. } => *single_block,
+            FormatDanglingComments::Comments { single_block, .. } => *single_block,
+        }
+    }
 }
 
 impl<Context> Format<Context> for FormatDanglingComments<'_, Context::Language>
@@ -237,6 +762,8 @@ where
 {
     fn fmt(&self, f: &mut Formatter<Context>) -> FormatResult<()> {
         let comments = f.context().comments().clone();
+        let force_break = f.context().dangling_comments_force_break();
+        let max_empty_lines = f.context().max_empty_lines();
         let dangling_comments = match self {
             FormatDanglingComments::Node { node, .. } => comments.dangling_comments(node),
             FormatDanglingComments::Comments { comments, .. } => *comments,
@@ -246,20 +773,56 @@ where
             return Ok(());
         }
 
+        let dangling_comments: Vec<_> = dangling_comments
+            .iter()
+            .filter(|comment| {
+                let is_consumed = comments.is_consumed_by_verbatim(comment);
+                if is_consumed {
+                    comment.mark_formatted();
+                }
+                !is_consumed
+            })
+            .collect();
+
+        if dangling_comments.is_empty() {
+            return Ok(());
+        }
+
+        let is_single_block = match dangling_comments.as_slice() {
+            [comment] => {
+                matches!(comment.kind(), CommentKind::InlineBlock) && comment.lines_before() == 0
+            }
+            _ => false,
+        };
+
+        // Line comments can't be followed by other content on the same line
+        let soft = (self.is_soft() || (self.is_group_if_single_block() && is_single_block))
+            && !force_break
+            && !dangling_comments
+                .iter()
+                .any(|comment| comment.kind().is_line());
+
         let format_dangling_comments = format_with(|f| {
             // Write all comments up to the first skipped token trivia or the token
-            let mut join = f.join_with(hard_line_break());
+            for (index, comment) in dangling_comments.iter().enumerate() {
+                if index > 0 {
+                    if soft {
+                        write!(f, [soft_line_break_or_space()])?;
+                    } else if self.has_preserve_gaps() {
+                        write!(
+                            f,
+                            [source_empty_lines(comment.lines_before(), max_empty_lines)]
+                        )?;
+                    } else {
+                        write!(f, [hard_line_break()])?;
+                    }
+                }
 
-            for comment in dangling_comments {
-                let format_comment =
-                    FormatRefWithRule::new(comment, Context::CommentRule::default());
-                join.entry(&format_comment);
+                write!(f, [FormatComment(comment)])?;
 
                 comment.mark_formatted();
             }
 
-            join.finish()?;
-
             if matches!(self.indent(), DanglingIndentMode::Soft)
                 && dangling_comments
                     .last()
@@ -272,12 +835,29 @@ where
         });
 
         match self.indent() {
+            DanglingIndentMode::Block if soft => {
+                write!(
+                    f,
+                    [group(&soft_space_or_block_indent(
+                        &format_dangling_comments
+                    ))]
+                )
+            }
             DanglingIndentMode::Block => {
                 write!(f, [block_indent(&format_dangling_comments)])
             }
+            DanglingIndentMode::Soft if force_break => {
+                write!(f, [block_indent(&format_dangling_comments)])
+            }
             DanglingIndentMode::Soft => {
                 write!(f, [group(&soft_block_indent(&format_dangling_comments))])
             }
+            DanglingIndentMode::None if soft => {
+                write!(f, [group(&format_dangling_comments)])
+            }
+            DanglingIndentMode::None if force_break => {
+                write!(f, [format_dangling_comments, expand_parent()])
+            }
             DanglingIndentMode::None => {
                 write!(f, [format_dangling_comments])
             }
@@ -287,6 +867,9 @@ where
 
 /// Formats a token without its skipped token trivia
 ///
+/// Prints the text returned by [CstFormatContext::normalize_token_text] instead of the source text
+/// if the context normalizes the token.
+///
 /// ## Warning
 /// It's your responsibility to format any skipped trivia.
 pub const fn format_trimmed_token<L: Language>(token: &SyntaxToken<L>) -> FormatTrimmedToken<L> {
@@ -304,7 +887,11 @@ where
 {
     fn fmt(&self, f: &mut Formatter<C>) -> FormatResult<()> {
         let trimmed_range = self.token.text_trimmed_range();
-        syntax_token_text_slice(self.token, trimmed_range).fmt(f)
+
+        match f.context().normalize_token_text(self.token) {
+            Some(text) => syntax_token_cow_slice(text, self.token, trimmed_range.start()).fmt(f),
+            None => syntax_token_text_slice(self.token, trimmed_range).fmt(f),
+        }
     }
 }
 /// Formats the skipped token trivia of a removed token and marks the token as tracked.
@@ -338,8 +925,8 @@ where
 
 /// Print out a `token` from the original source with a different `content`.
 ///
-/// This will print the skipped token trivia that belong to `token` to `content`;
-/// `token` is then marked as consumed by the formatter.
+/// This will print the skipped token trivia that belong to `token` to `content`,
+/// unless [FormatReplaced::without_trivia] is called; `token` is then marked as consumed by the formatter.
 pub fn format_replaced<'a, 'content, L, Context>(
     token: &'a SyntaxToken<L>,
     content: &'content impl Format<Context>,
@@ -350,6 +937,7 @@ where
     FormatReplaced {
         token,
         content: Argument::new(content),
+        with_trivia: true,
     }
 }
 
@@ -362,6 +950,19 @@ where
 {
     token: &'a SyntaxToken<L>,
     content: Argument<'content, C>,
+    with_trivia: bool,
+}
+
+impl<'a, 'content, L, C> FormatReplaced<'a, 'content, L, C>
+where
+    L: Language,
+{
+    /// Omits the skipped token trivia of the token from the formatted output, for a caller
+    /// that formats it itself. The token is still marked as consumed by the formatter.
+    pub fn without_trivia(mut self) -> Self {
+        self.with_trivia = false;
+        self
+    }
 }
 
 impl<L, C> Format<C> for FormatReplaced<'_, '_, L, C>
@@ -372,13 +973,20 @@ where
     fn fmt(&self, f: &mut Formatter<C>) -> FormatResult<()> {
         f.state_mut().track_token(self.token);
 
-        write!(f, [format_skipped_token_trivia(self.token)])?;
+        if self.with_trivia {
+            write!(f, [format_skipped_token_trivia(self.token)])?;
+        }
 
         f.write_fmt(Arguments::from(&self.content))
     }
 }
 
 /// Formats the given token only if the group does break and otherwise retains the token's skipped token trivia.
+///
+/// This is the formatting of the trailing comma of a list: `[1, 2,]` collapses to `[1, 2]` if
+/// the list fits on a single line. Removing the token never drops a comment because the comments
+/// around a token are attached to the nodes: the comment in `[1, 2, /* comment */]` is a trailing
+/// comment of `2` and is formatted by the preceding element, `[1, 2 /* comment */]`.
 pub fn format_only_if_breaks<'a, 'content, L, Content, Context>(
     token: &'a SyntaxToken<L>,
     content: &'content Content,
@@ -441,6 +1049,68 @@ where
     }
 }
 
+/// Formats the given content only if the group fits on a single line and otherwise retains the token's
+/// skipped token trivia. This is the inverse of [format_only_if_breaks].
+pub fn format_only_if_fits<'a, 'content, L, Content, Context>(
+    token: &'a SyntaxToken<L>,
+    content: &'content Content,
+) -> FormatOnlyIfFits<'a, 'content, L, Context>
+where
+    L: Language,
+    Content: Format<Context>,
+{
+    FormatOnlyIfFits {
+        token,
+        content: Argument::new(content),
+        group_id: None,
+    }
+}
+
+/// Formats content that only gets printed if its enclosing group fits on a single line,
+/// and the skipped token trivia of a token that only gets printed if the group breaks.
+pub struct FormatOnlyIfFits<'a, 'content, L, C>
+where
+    L: Language,
+{
+    token: &'a SyntaxToken<L>,
+    content: Argument<'content, C>,
+    group_id: Option<GroupId>,
+}
+
+impl<'a, 'content, L, C> FormatOnlyIfFits<'a, 'content, L, C>
+where
+    L: Language,
+{
+    pub fn with_group_id(mut self, group_id: Option<GroupId>) -> Self {
+        self.group_id = group_id;
+        self
+    }
+}
+
+impl<L, C> Format<C> for FormatOnlyIfFits<'_, '_, L, C>
+where
+    L: Language + 'static,
+    C: CstFormatContext<Language = L>,
+{
+    fn fmt(&self, f: &mut Formatter<C>) -> FormatResult<()> {
+        write!(
+            f,
+            [if_group_fits_on_line(&Arguments::from(&self.content)).with_group_id(self.group_id),]
+        )?;
+
+        if f.comments().has_skipped(self.token) {
+            // Print the trivia otherwise
+            write!(
+                f,
+                [if_group_breaks(&format_skipped_token_trivia(self.token))
+                    .with_group_id(self.group_id)]
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
 /// Formats the skipped token trivia of `token`.
 pub const fn format_skipped_token_trivia<L: Language>(
     token: &SyntaxToken<L>,
@@ -459,6 +1129,8 @@ impl<L: Language> FormatSkippedTokenTrivia<'_, L> {
     where
         Context: CstFormatContext<Language = L>,
     {
+        let max_empty_lines = f.context().max_empty_lines();
+
         // Lines/spaces before the next token/comment
         let (mut lines, mut spaces) = match self.token.prev_token() {
             Some(token) => {
@@ -477,6 +1149,8 @@ impl<L: Language> FormatSkippedTokenTrivia<'_, L> {
 
                 (lines, spaces)
             }
+            // The first token of the file: the loop below counts the lines and spaces of
+            // its leading trivia that precede the first skipped token trivia
             None => (0, 0),
         };
 
@@ -524,8 +1198,7 @@ impl<L: Language> FormatSkippedTokenTrivia<'_, L> {
                         } else {
                             match lines {
                                 0 => write!(f, [space()])?,
-                                1 => write!(f, [hard_line_break()])?,
-                                _ => write!(f, [empty_line()])?,
+                                lines => write!(f, [source_empty_lines(lines, max_empty_lines)])?,
                             };
                         }
 
@@ -563,15 +1236,17 @@ impl<L: Language> FormatSkippedTokenTrivia<'_, L> {
         } else {
             match dangling_comments.first().unwrap().lines_before {
                 0 => write!(f, [space()])?,
-                1 => write!(f, [hard_line_break()])?,
-                _ => write!(f, [empty_line()])?,
+                lines => write!(f, [source_empty_lines(lines, max_empty_lines)])?,
             }
 
             write!(
                 f,
                 [FormatDanglingComments::Comments {
                     comments: &dangling_comments,
-                    indent: DanglingIndentMode::None
+                    indent: DanglingIndentMode::None,
+                    preserve_gaps: false,
+                    soft: false,
+                    single_block: false,
                 }]
             )?;
 
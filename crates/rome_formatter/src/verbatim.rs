@@ -50,6 +50,12 @@ where
             }
         }
 
+        // The verbatim text contains all the comments inside of the node, including comments
+        // attached to a node outside of it: prevent the comment builders from printing them again.
+        f.context()
+            .comments()
+            .mark_verbatim_range(self.node.text_trimmed_range());
+
         // The trimmed range of a node is its range without any of its leading or trailing trivia.
         // Except for nodes that used to be parenthesized, the range than covers the source from the
         // `(` to the `)` (the trimmed range of the parenthesized expression, not the inner expression)
@@ -135,7 +141,7 @@ where
                 comment.mark_formatted();
             }
 
-            write!(f, [FormatTrailingComments::Comments(outside_trimmed_range)])?;
+            write!(f, [FormatTrailingComments::comments(outside_trimmed_range)])?;
         }
 
         f.write_element(FormatElement::Tag(Tag::EndVerbatim))
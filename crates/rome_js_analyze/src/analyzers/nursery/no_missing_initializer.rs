@@ -0,0 +1,157 @@
+use crate::JsRuleAction;
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    JsAnyBindingPattern, JsAnyExpression, JsSyntaxKind, JsVariableDeclaration,
+    JsVariableDeclarator, SourceType, TriviaPieceKind, TsAnyVariableAnnotation, T,
+};
+use rome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
+
+declare_rule! {
+    /// Require `const` and `let` declarations to have an initializer.
+    ///
+    /// A `const` declaration without an initializer is a syntax error. The rule
+    /// suggests initializing the variable with `undefined`. A `let` declaration without
+    /// an initializer is only reported.
+    ///
+    /// The declarations of `for` loops and the ambient declarations of TypeScript
+    /// are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// let a;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const a = 1;
+    /// let b = undefined;
+    /// ```
+    ///
+    /// ```js
+    /// for (let i of list) {}
+    /// ```
+    ///
+    /// ```js
+    /// var c;
+    /// ```
+    pub(crate) NoMissingInitializer {
+        version: "10.0.0",
+        name: "noMissingInitializer",
+        recommended: false,
+    }
+}
+
+impl Rule for NoMissingInitializer {
+    type Query = Ast<JsVariableDeclaration>;
+    type State = JsVariableDeclarator;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let declaration = ctx.query();
+
+        if declaration.is_var()
+            || declaration.syntax().parent().map_or(false, |parent| {
+                parent.kind() == JsSyntaxKind::JS_FOR_STATEMENT
+            })
+        {
+            return Vec::new();
+        }
+
+        // `declare const a: number;` doesn't need an initializer, nor any declaration
+        // of a definition file when the analyzer provides the source type of the file
+        if ctx
+            .source_type::<SourceType>()
+            .map_or(false, |source_type| {
+                source_type.language().is_definition_file()
+            })
+            || declaration
+                .syntax()
+                .ancestors()
+                .any(|ancestor| ancestor.kind() == JsSyntaxKind::TS_DECLARE_STATEMENT)
+        {
+            return Vec::new();
+        }
+
+        declaration
+            .declarators()
+            .iter()
+            .filter_map(Result::ok)
+            .filter(|declarator| declarator.initializer().is_none())
+            .collect()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let kind = ctx.query().kind().ok()?;
+        let name = state.id().ok()?.syntax().text_trimmed().to_string();
+
+        Some(RuleDiagnostic::new(
+            rule_category!(),
+            state.range(),
+            markup! {
+                "The "<Emphasis>{kind.text_trimmed()}</Emphasis>" declaration of "<Emphasis>{name}</Emphasis>" has no initializer."
+            },
+        ))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        if !ctx.query().is_const() {
+            return None;
+        }
+
+        // Initializing a destructuring pattern with `undefined` throws, and a definite
+        // assignment assertion can't have an initializer
+        if !matches!(state.id().ok()?, JsAnyBindingPattern::JsAnyBinding(_))
+            || matches!(
+                state.variable_annotation(),
+                Some(TsAnyVariableAnnotation::TsDefiniteVariableAnnotation(_))
+            )
+        {
+            return None;
+        }
+
+        // Reuses the whitespace before a `;` for the space before the `=`
+        let has_trailing_whitespace = state.syntax().last_token().map_or(false, |token| {
+            token
+                .trailing_trivia()
+                .pieces()
+                .last()
+                .map_or(false, |piece| piece.is_whitespace())
+        });
+
+        let eq_token = if has_trailing_whitespace {
+            make::token(T![=]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")])
+        } else {
+            make::token_decorated_with_space(T![=])
+        };
+
+        let initializer = make::js_initializer_clause(
+            eq_token,
+            JsAnyExpression::from(make::js_identifier_expression(
+                make::js_reference_identifier(make::ident("undefined")),
+            )),
+        );
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node_discard_trivia(
+            state.clone(),
+            state.clone().with_initializer(Some(initializer)),
+        );
+
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            // The code using the variable may not expect `undefined`
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Initialize the constant with "<Emphasis>"undefined"</Emphasis>"." }
+                .to_owned(),
+            mutation,
+        })
+    }
+}
@@ -1,6 +1,8 @@
 //! Generated file, do not edit by hand, see `xtask/codegen`
 
 use rome_analyze::declare_group;
+mod add_const_assertion;
 mod flip_bin_exp;
 mod inline_variable;
-declare_group! { pub (crate) Correctness { name : "correctness" , rules : [self :: flip_bin_exp :: FlipBinExp , self :: inline_variable :: InlineVariable ,] } }
+mod merge_variable_declarations;
+declare_group! { pub (crate) Correctness { name : "correctness" , rules : [self :: add_const_assertion :: AddConstAssertion , self :: flip_bin_exp :: FlipBinExp , self :: inline_variable :: InlineVariable , self :: merge_variable_declarations :: MergeVariableDeclarations ,] } }
@@ -0,0 +1,98 @@
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    JsAnyExpression, JsSyntaxKind, JsVariableDeclarationClause, SourceType, TsAnyName, TsType, T,
+};
+use rome_rowan::{AstNode, AstNodeExt, AstSeparatedList, BatchMutationExt};
+
+use crate::JsRuleAction;
+
+declare_rule! {
+    /// Provides a refactor to add a `const` assertion to the object or array literal
+    /// initializing a `const` declaration of a TypeScript file.
+    ///
+    /// The refactor isn't offered if the variable has a type annotation or if the
+    /// initializer isn't an object or array literal, for example because it already has a type assertion.
+    ///
+    /// ## Examples
+    ///
+    /// ```ts
+    /// const directions = ["up", "down"];
+    /// ```
+    pub(crate) AddConstAssertion {
+        version: "10.0.0",
+        name: "addConstAssertion",
+        recommended: false,
+    }
+}
+
+impl Rule for AddConstAssertion {
+    type Query = Ast<JsVariableDeclarationClause>;
+    type State = JsAnyExpression;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        // `as const` is a TypeScript assertion, skip the files known not to be TypeScript
+        if ctx
+            .source_type::<SourceType>()
+            .map_or(false, |source_type| !source_type.language().is_typescript())
+        {
+            return Vec::new();
+        }
+
+        let declaration = match ctx.query().declaration() {
+            Ok(declaration) if declaration.is_const() => declaration,
+            _ => return Vec::new(),
+        };
+
+        declaration
+            .declarators()
+            .iter()
+            .filter_map(Result::ok)
+            // `const a: number[] = [1] as const` is a type error
+            .filter(|declarator| declarator.variable_annotation().is_none())
+            .filter_map(|declarator| declarator.initializer()?.expression().ok())
+            .filter(|expression| {
+                matches!(
+                    expression.syntax().kind(),
+                    JsSyntaxKind::JS_OBJECT_EXPRESSION | JsSyntaxKind::JS_ARRAY_EXPRESSION
+                )
+            })
+            .collect()
+    }
+
+    fn action(ctx: &RuleContext<Self>, expression: &Self::State) -> Option<JsRuleAction> {
+        // The trailing trivia of the literal, like a comment, moves after the assertion
+        let last_token = expression.syntax().last_token()?;
+        let literal = expression.clone().replace_token_discard_trivia(
+            last_token.clone(),
+            last_token.with_trailing_trivia([]),
+        )?;
+
+        let const_type = TsType::from(
+            make::ts_reference_type(TsAnyName::JsReferenceIdentifier(
+                make::js_reference_identifier(make::ident("const")),
+            ))
+            .build(),
+        );
+        let assertion = make::ts_as_expression(
+            literal,
+            make::token_decorated_with_space(T![as]),
+            const_type,
+        );
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(expression.clone(), JsAnyExpression::from(assertion));
+
+        Some(JsRuleAction {
+            category: ActionCategory::Refactor,
+            // The code using the variable may rely on its mutable type
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Add a "<Emphasis>"const"</Emphasis>" assertion" }.to_owned(),
+            mutation,
+        })
+    }
+}
@@ -0,0 +1,157 @@
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    JsModuleItemList, JsStatementList, JsVariableKind, JsVariableStatement,
+    JsVariableStatementFields, T,
+};
+use rome_rowan::{AstNode, AstNodeExt, AstSeparatedList, BatchMutationExt};
+
+use crate::JsRuleAction;
+
+declare_rule! {
+    /// Provides a refactor to merge a `const` or `let` statement with the next statement
+    /// declaring its variables with the same keyword.
+    ///
+    /// The refactor isn't offered if a comment separates the two statements. The trailing
+    /// comment of the first statement is kept after its last declarator.
+    ///
+    /// ## Examples
+    ///
+    /// ```js
+    /// const a = 1; // first
+    /// const b = 2; // second
+    /// ```
+    pub(crate) MergeVariableDeclarations {
+        version: "10.0.0",
+        name: "mergeVariableDeclarations",
+        recommended: false,
+    }
+}
+
+impl Rule for MergeVariableDeclarations {
+    type Query = Ast<JsVariableStatement>;
+    type State = JsVariableStatement;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let statement = ctx.query();
+
+        let parent = statement.syntax().parent()?;
+        if !JsStatementList::can_cast(parent.kind()) && !JsModuleItemList::can_cast(parent.kind()) {
+            return None;
+        }
+
+        let next = JsVariableStatement::cast(statement.syntax().next_sibling()?)?;
+
+        let declaration = statement.declaration().ok()?;
+        let next_declaration = next.declaration().ok()?;
+
+        let kind = declaration.variable_kind().ok()?;
+        if !matches!(kind, JsVariableKind::Const | JsVariableKind::Let)
+            || next_declaration.variable_kind().ok()? != kind
+        {
+            return None;
+        }
+
+        // A comment before or after the keyword of the next statement has nowhere to go
+        let next_keyword = next_declaration.kind().ok()?;
+        if next_keyword.has_leading_comments() || next_keyword.has_trailing_comments() {
+            return None;
+        }
+
+        // Bail on syntax errors in either list
+        for declarator in declaration
+            .declarators()
+            .iter()
+            .chain(next_declaration.declarators().iter())
+        {
+            declarator.ok()?;
+        }
+
+        Some(next)
+    }
+
+    fn action(ctx: &RuleContext<Self>, next: &Self::State) -> Option<JsRuleAction> {
+        let statement = ctx.query();
+
+        let JsVariableStatementFields {
+            declaration,
+            semicolon_token,
+        } = statement.as_fields();
+        let declaration = declaration.ok()?;
+        let next_declaration = next.declaration().ok()?;
+
+        let mut declarators = declaration
+            .declarators()
+            .iter()
+            .collect::<Result<Vec<_>, _>>()
+            .ok()?;
+        let mut separators = declaration
+            .declarators()
+            .separators()
+            .collect::<Result<Vec<_>, _>>()
+            .ok()?;
+
+        // Move the trailing trivia of the first statement, e.g. a comment following its
+        // semicolon, after the comma separating the two declarator lists
+        let trailing_trivia = match semicolon_token {
+            Some(semicolon_token) => semicolon_token.trailing_trivia().pieces().collect(),
+            None => {
+                let last = declarators.pop()?;
+                let last_token = last.syntax().last_token()?;
+                let trivia = last_token.trailing_trivia().pieces().collect::<Vec<_>>();
+                declarators.push(last.replace_token_discard_trivia(
+                    last_token.clone(),
+                    last_token.with_trailing_trivia([]),
+                )?);
+                trivia
+            }
+        };
+        separators.push(make::token(T![,]).with_trailing_trivia_pieces(trailing_trivia));
+
+        // The first declarator of the next statement takes the place of its keyword
+        let next_keyword = next_declaration.kind().ok()?;
+        for (index, declarator) in next_declaration.declarators().iter().enumerate() {
+            let mut declarator = declarator.ok()?;
+
+            if index == 0 {
+                let first_token = declarator.syntax().first_token()?;
+                declarator = declarator.replace_token_discard_trivia(
+                    first_token.clone(),
+                    first_token.with_leading_trivia_pieces(next_keyword.leading_trivia().pieces()),
+                )?;
+            }
+
+            declarators.push(declarator);
+        }
+
+        for separator in next_declaration.declarators().separators() {
+            separators.push(separator.ok()?);
+        }
+
+        let merged = make::js_variable_declaration(
+            declaration.kind().ok()?,
+            make::js_variable_declarator_list(declarators, separators),
+        );
+        let merged = match next.semicolon_token() {
+            Some(semicolon_token) => make::js_variable_statement(merged)
+                .with_semicolon_token(semicolon_token)
+                .build(),
+            None => make::js_variable_statement(merged).build(),
+        };
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node_discard_trivia(statement.clone(), merged);
+        mutation.remove_node(next.clone());
+
+        Some(JsRuleAction {
+            category: ActionCategory::Refactor,
+            applicability: Applicability::Always,
+            message: markup! { "Merge the variable declarations" }.to_owned(),
+            mutation,
+        })
+    }
+}
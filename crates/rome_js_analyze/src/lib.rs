@@ -1,12 +1,12 @@
 use control_flow::make_visitor;
 use rome_analyze::{
-    AnalysisFilter, Analyzer, AnalyzerContext, AnalyzerOptions, AnalyzerSignal, ControlFlow,
-    InspectMatcher, LanguageRoot, MatchQueryParams, MetadataRegistry, Phases, RuleAction,
-    RuleRegistry, ServiceBag, SyntaxVisitor,
+    AnalysisFilter, Analyzer, AnalyzerContext, AnalyzerOptions, AnalyzerSignal, AnalyzerSummary,
+    ControlFlow, InspectMatcher, LanguageRoot, MatchQueryParams, MetadataRegistry, Phases,
+    RuleAction, RuleRegistry, ServiceBag, SuppressionScope, SyntaxVisitor,
 };
 use rome_diagnostics::file::FileId;
 use rome_js_syntax::{
-    suppression::{parse_suppression_comment, SuppressionCategory},
+    suppression::{self, parse_suppression_comment, SuppressionCategory},
     JsLanguage,
 };
 use serde::{Deserialize, Serialize};
@@ -20,11 +20,13 @@ mod react;
 mod registry;
 mod semantic_analyzers;
 mod semantic_services;
+mod suppressions;
 mod syntax;
 pub mod utils;
 
 pub use crate::registry::visit_registry;
 use crate::semantic_services::{SemanticModelBuilderVisitor, SemanticModelVisitor};
+use crate::suppressions::suppression_hook;
 
 pub(crate) type JsRuleAction = RuleAction<JsLanguage>;
 
@@ -53,19 +55,62 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     filter: AnalysisFilter,
     inspect_matcher: V,
     options: &'a AnalyzerOptions,
-    mut emit_signal: F,
+    emit_signal: F,
 ) -> Option<B>
 where
     V: FnMut(&MatchQueryParams<JsLanguage>) + 'a,
     F: FnMut(&dyn AnalyzerSignal<JsLanguage>) -> ControlFlow<B> + 'a,
     B: 'a,
 {
-    fn parse_linter_suppression_comment(text: &str) -> Vec<Option<&str>> {
+    run_analyzer(file_id, root, filter, inspect_matcher, options, emit_signal).0
+}
+
+/// Run the analyzer on the provided `root` like [analyze], and additionally
+/// return how many diagnostics were emitted and how many were suppressed by a
+/// `rome-ignore` comment
+pub fn analyze_with_summary<'a, F, B>(
+    file_id: FileId,
+    root: &LanguageRoot<JsLanguage>,
+    filter: AnalysisFilter,
+    options: &'a AnalyzerOptions,
+    emit_signal: F,
+) -> (Option<B>, AnalyzerSummary)
+where
+    F: FnMut(&dyn AnalyzerSignal<JsLanguage>) -> ControlFlow<B> + 'a,
+    B: 'a,
+{
+    run_analyzer(file_id, root, filter, |_| {}, options, emit_signal)
+}
+
+fn run_analyzer<'a, V, F, B>(
+    file_id: FileId,
+    root: &LanguageRoot<JsLanguage>,
+    filter: AnalysisFilter,
+    inspect_matcher: V,
+    options: &'a AnalyzerOptions,
+    mut emit_signal: F,
+) -> (Option<B>, AnalyzerSummary)
+where
+    V: FnMut(&MatchQueryParams<JsLanguage>) + 'a,
+    F: FnMut(&dyn AnalyzerSignal<JsLanguage>) -> ControlFlow<B> + 'a,
+    B: 'a,
+{
+    fn parse_linter_suppression_comment(text: &str) -> Vec<(SuppressionScope, Option<&str>)> {
         parse_suppression_comment(text)
-            .flat_map(|comment| comment.categories)
-            .filter_map(|(key, value)| {
+            .flat_map(|comment| {
+                let scope = match comment.scope {
+                    suppression::SuppressionScope::Line => SuppressionScope::Line,
+                    suppression::SuppressionScope::File => SuppressionScope::File,
+                };
+
+                comment
+                    .categories
+                    .into_iter()
+                    .map(move |category| (scope, category))
+            })
+            .filter_map(|(scope, (key, value))| {
                 if key == SuppressionCategory::Lint {
-                    Some(value)
+                    Some((scope, value))
                 } else {
                     None
                 }
@@ -89,11 +134,14 @@ where
     analyzer.add_visitor(Phases::Semantic, SemanticModelVisitor);
     analyzer.add_visitor(Phases::Semantic, SyntaxVisitor::default());
 
-    analyzer.run(AnalyzerContext {
+    let mut services = ServiceBag::default();
+    services.insert_service(suppression_hook());
+
+    analyzer.run_with_summary(AnalyzerContext {
         file_id,
         root: root.clone(),
         range: filter.range,
-        services: ServiceBag::default(),
+        services,
         options,
     })
 }
@@ -118,7 +166,10 @@ where
 #[cfg(test)]
 mod tests {
 
-    use rome_analyze::{AnalyzerOptions, Never, RuleCategories};
+    use rome_analyze::{
+        ActionCategory, AnalyzerOptions, AnalyzerSummary, Never, RuleCategories, RuleFilter,
+        SuppressionStyle,
+    };
     use rome_console::fmt::{Formatter, Termcolor};
     use rome_console::{markup, Markup};
     use rome_diagnostics::termcolor::NoColor;
@@ -127,7 +178,7 @@ mod tests {
     use rome_js_parser::parse;
     use rome_js_syntax::{SourceType, TextRange, TextSize};
 
-    use crate::{analyze, AnalysisFilter, ControlFlow};
+    use crate::{analyze, analyze_with_summary, AnalysisFilter, ControlFlow};
 
     #[ignore]
     #[test]
@@ -235,6 +286,416 @@ mod tests {
         );
     }
 
+    const NO_DOUBLE_EQUALS: &[RuleFilter<'static>] =
+        &[RuleFilter::Rule("correctness", "noDoubleEquals")];
+
+    /// Analyzes `source` with the `noDoubleEquals` rule and returns the code resulting from
+    /// applying each action of `category`
+    fn apply_actions(
+        source: &str,
+        source_type: SourceType,
+        options: &AnalyzerOptions,
+        category: ActionCategory,
+    ) -> Vec<String> {
+        let parsed = parse(source, FileId::zero(), source_type);
+
+        let filter = AnalysisFilter {
+            enabled_rules: Some(NO_DOUBLE_EQUALS),
+            ..AnalysisFilter::default()
+        };
+
+        let mut fixed = Vec::new();
+        analyze(FileId::zero(), &parsed.tree(), filter, options, |signal| {
+            for action in signal.actions() {
+                if action.category == category {
+                    fixed.push(action.mutation.commit().to_string());
+                }
+            }
+
+            ControlFlow::<Never>::Continue(())
+        });
+
+        fixed
+    }
+
+    /// Analyzes `source`, which must be free of syntax errors, with the rules enabled by
+    /// `rules` and returns the counts of its diagnostics
+    fn summarize(
+        source: &str,
+        source_type: SourceType,
+        rules: &[RuleFilter],
+        options: &AnalyzerOptions,
+    ) -> AnalyzerSummary {
+        let parsed = parse(source, FileId::zero(), source_type);
+        assert!(!parsed.has_errors(), "{source}");
+
+        let filter = AnalysisFilter {
+            enabled_rules: Some(rules),
+            ..AnalysisFilter::default()
+        };
+
+        let (_, summary) =
+            analyze_with_summary(FileId::zero(), &parsed.tree(), filter, options, |_| {
+                ControlFlow::<Never>::Continue(())
+            });
+
+        summary
+    }
+
+    #[test]
+    fn suppression_action_with_todo() {
+        const SOURCE: &str = "function f(a, b) {
+    return a == b;
+}
+";
+
+        let options = AnalyzerOptions {
+            suppression_with_todo: true,
+            ..AnalyzerOptions::default()
+        };
+
+        assert_eq!(
+            apply_actions(
+                SOURCE,
+                SourceType::js_module(),
+                &options,
+                ActionCategory::Suppression
+            ),
+            ["function f(a, b) {
+    // TODO(noDoubleEquals): Use === instead of ==
+    // rome-ignore lint(correctness/noDoubleEquals): <explanation>
+    return a == b;
+}
+"]
+        );
+    }
+
+    #[test]
+    fn action_span() {
+        const SOURCE: &str = "function f(a, b) {
+    return a == b;
+}
+";
+
+        let parsed = parse(SOURCE, FileId::zero(), SourceType::js_module());
+
+        let filter = AnalysisFilter {
+            enabled_rules: Some(NO_DOUBLE_EQUALS),
+            ..AnalysisFilter::default()
+        };
+
+        let options = AnalyzerOptions {
+            file_suppression: true,
+            ..AnalyzerOptions::default()
+        };
+
+        let mut spans = Vec::new();
+        analyze(FileId::zero(), &parsed.tree(), filter, &options, |signal| {
+            for action in signal.actions() {
+                spans.push((action.category, action.span));
+            }
+
+            ControlFlow::<Never>::Continue(())
+        });
+
+        assert_eq!(
+            spans,
+            [
+                // The binary expression matched by the rule
+                (
+                    ActionCategory::QuickFix,
+                    Some(TextRange::new(TextSize::from(30), TextSize::from(36)))
+                ),
+                // The start of the line of the `==` operator the diagnostic points at,
+                // where the suppression comment is inserted
+                (
+                    ActionCategory::Suppression,
+                    Some(TextRange::empty(TextSize::from(19)))
+                ),
+                // The start of the file, where the `rome-ignore-all` comment is inserted
+                (
+                    ActionCategory::FileSuppression,
+                    Some(TextRange::empty(TextSize::from(0)))
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn next_line_suppression_action() {
+        let options = AnalyzerOptions {
+            suppression_style: SuppressionStyle::NextLine,
+            ..AnalyzerOptions::default()
+        };
+
+        let cases = [
+            (
+                "function f(a, b) {
+    return a == b;
+}
+",
+                "function f(a, b) {
+    // rome-ignore-next-line lint(correctness/noDoubleEquals): <explanation>
+    return a == b;
+}
+",
+            ),
+            // The comment is inserted above the line of the `if` statement, before
+            // the statement starting that line
+            (
+                "function f(a, b) {
+    g(); if (a == b) {
+        return a;
+    }
+}
+",
+                "function f(a, b) {
+    // rome-ignore-next-line lint(correctness/noDoubleEquals): <explanation>
+    g(); if (a == b) {
+        return a;
+    }
+}
+",
+            ),
+        ];
+
+        for (source, expected) in cases {
+            let source_type = SourceType::js_module();
+            let fixed = apply_actions(source, source_type, &options, ActionCategory::Suppression);
+            assert_eq!(fixed, [expected]);
+
+            let summary = summarize(expected, source_type, NO_DOUBLE_EQUALS, &options);
+            assert_eq!(summary.emitted, 0, "{expected}");
+        }
+    }
+
+    #[test]
+    fn switch_case_suppression_action() {
+        const SOURCE: &str = "switch (a) {
+    case 1:
+        f(a == 1);
+    case 2: f(a == 2);
+    default:
+\t\tf(a == 3);
+}
+";
+
+        let source_type = SourceType::js_module();
+        let options = AnalyzerOptions::default();
+
+        let suppressions =
+            apply_actions(SOURCE, source_type, &options, ActionCategory::Suppression);
+
+        assert_eq!(
+            suppressions,
+            [
+                "switch (a) {
+    case 1:
+        // rome-ignore lint(correctness/noDoubleEquals): <explanation>
+        f(a == 1);
+    case 2: f(a == 2);
+    default:
+\t\tf(a == 3);
+}
+",
+                "switch (a) {
+    case 1:
+        f(a == 1);
+    // rome-ignore lint(correctness/noDoubleEquals): <explanation>
+    case 2: f(a == 2);
+    default:
+\t\tf(a == 3);
+}
+",
+                "switch (a) {
+    case 1:
+        f(a == 1);
+    case 2: f(a == 2);
+    default:
+\t\t// rome-ignore lint(correctness/noDoubleEquals): <explanation>
+\t\tf(a == 3);
+}
+"
+            ]
+        );
+
+        for suppressed in &suppressions {
+            let summary = summarize(suppressed, source_type, NO_DOUBLE_EQUALS, &options);
+            assert_eq!(summary.emitted, 2, "{suppressed}");
+        }
+    }
+
+    #[test]
+    fn jsx_suppression_action() {
+        const SOURCE: &str = "const a = (
+    <div>
+        text
+        <a onClick={a == b} />
+    </div>
+);
+";
+
+        let source_type = SourceType::jsx();
+        let options = AnalyzerOptions::default();
+
+        let fixed = apply_actions(SOURCE, source_type, &options, ActionCategory::Suppression);
+        assert_eq!(
+            fixed,
+            ["const a = (
+    <div>
+        text
+        {/* rome-ignore lint(correctness/noDoubleEquals): <explanation> */}
+        <a onClick={a == b} />
+    </div>
+);
+"]
+        );
+
+        let summary = summarize(&fixed[0], source_type, NO_DOUBLE_EQUALS, &options);
+        assert_eq!(summary.emitted, 0);
+    }
+
+    #[test]
+    fn inline_suppression_action() {
+        // The suppression of a line containing a single-line literal or arrow body is a block
+        // comment, inserted on its own line above the line like the line comments
+        let cases = [
+            (
+                "const a = { b: a == b };\n",
+                "/* rome-ignore lint(correctness/noDoubleEquals): <explanation> */\nconst a = { b: a == b };\n",
+            ),
+            (
+                "const a = [a == b];\n",
+                "/* rome-ignore lint(correctness/noDoubleEquals): <explanation> */\nconst a = [a == b];\n",
+            ),
+            (
+                "const f = () => a == b;\n",
+                "/* rome-ignore lint(correctness/noDoubleEquals): <explanation> */\nconst f = () => a == b;\n",
+            ),
+            (
+                "const f = () =>\n    a == b;\n",
+                "const f = () =>\n    // rome-ignore lint(correctness/noDoubleEquals): <explanation>\n    a == b;\n",
+            ),
+        ];
+
+        let source_type = SourceType::js_module();
+        let options = AnalyzerOptions::default();
+
+        for (source, expected) in cases {
+            let fixed = apply_actions(source, source_type, &options, ActionCategory::Suppression);
+            assert_eq!(fixed, [expected], "source: {source:?}");
+
+            let summary = summarize(expected, source_type, NO_DOUBLE_EQUALS, &options);
+            assert_eq!(summary.emitted, 0, "source: {source:?}");
+        }
+    }
+
+    #[test]
+    fn suppression_summary() {
+        const SOURCE: &str = "function f(a, b) {
+    // rome-ignore lint(correctness/noDoubleEquals): explanation
+    a == b;
+    return a == b;
+}
+";
+
+        // The signals of the `flipBinExp` assist have no diagnostic and aren't counted
+        let rules = [
+            RuleFilter::Rule("correctness", "noDoubleEquals"),
+            RuleFilter::Rule("correctness", "flipBinExp"),
+        ];
+
+        let summary = summarize(
+            SOURCE,
+            SourceType::js_module(),
+            &rules,
+            &AnalyzerOptions::default(),
+        );
+
+        assert_eq!(summary.emitted, 1);
+        assert_eq!(summary.suppressed, 1);
+    }
+
+    #[test]
+    fn file_suppression_action() {
+        const SOURCE: &str = "// header
+function f(a, b) {
+    return a == b;
+}
+
+function g(a, b) {
+    return a == b;
+}
+";
+
+        let source_type = SourceType::js_module();
+        let options = AnalyzerOptions {
+            file_suppression: true,
+            ..AnalyzerOptions::default()
+        };
+
+        let fixed = apply_actions(
+            SOURCE,
+            source_type,
+            &options,
+            ActionCategory::FileSuppression,
+        );
+
+        assert_eq!(
+            fixed,
+            ["// rome-ignore-all lint(correctness/noDoubleEquals): <explanation>
+// header
+function f(a, b) {
+    return a == b;
+}
+
+function g(a, b) {
+    return a == b;
+}
+"; 2]
+        );
+
+        // The comment suppresses the rule on every line of the file
+        let summary = summarize(&fixed[0], source_type, NO_DOUBLE_EQUALS, &options);
+        assert_eq!(summary.emitted, 0);
+        assert_eq!(summary.suppressed, 2);
+    }
+
+    #[test]
+    fn misplaced_file_suppression() {
+        const SOURCE: &str = "function f(a, b) {
+    // rome-ignore-all lint(correctness/noDoubleEquals) lint(correctness/useWhile): not in the header
+    return a == b;
+}
+";
+
+        let parsed = parse(SOURCE, FileId::zero(), SourceType::js_module());
+
+        let filter = AnalysisFilter {
+            enabled_rules: Some(NO_DOUBLE_EQUALS),
+            ..AnalysisFilter::default()
+        };
+
+        let mut categories = Vec::new();
+        let options = AnalyzerOptions::default();
+        analyze(FileId::zero(), &parsed.tree(), filter, &options, |signal| {
+            if let Some(diag) = signal.diagnostic() {
+                categories.push(diag.category().unwrap());
+            }
+
+            ControlFlow::<Never>::Continue(())
+        });
+
+        // The comment doesn't suppress the rule and is reported once
+        assert_eq!(
+            categories,
+            [
+                category!("suppressions/misplacedFileSuppression"),
+                category!("lint/correctness/noDoubleEquals"),
+            ]
+        );
+    }
+
     #[test]
     fn suppression_syntax() {
         const SOURCE: &str = "
@@ -0,0 +1,139 @@
+use crate::{semantic_services::Semantic, JsRuleAction};
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_semantic::AllReferencesExtensions;
+use rome_js_syntax::{JsIdentifierBinding, JsSyntaxKind, JsVariableDeclaration, T};
+use rome_rowan::{AstNode, BatchMutationExt};
+
+declare_rule! {
+    /// Require `const` declarations for variables that are never reassigned after declared.
+    ///
+    /// The rule only reports the `let` declarations whose variables all have an initializer
+    /// and are never reassigned, including in destructuring patterns and in closures.
+    /// The variables declared in the initializer of a `for` loop are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// let a = 3;
+    /// console.log(a);
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// let { a, b } = object;
+    /// console.log(a, b);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// let a = 3;
+    /// a = 4;
+    /// ```
+    ///
+    /// ```js
+    /// let { a, b } = object;
+    /// function f() {
+    ///     b = 2;
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// let a;
+    /// ```
+    ///
+    /// ```js
+    /// for (let i = 0; i < 10; i++) {}
+    /// ```
+    pub(crate) UseConst {
+        version: "10.0.0",
+        name: "useConst",
+        recommended: false,
+    }
+}
+
+impl Rule for UseConst {
+    type Query = Semantic<JsVariableDeclaration>;
+    type State = Vec<JsIdentifierBinding>;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let declaration = ctx.query();
+        let model = ctx.model();
+
+        if !declaration.is_let()
+            || declaration.syntax().parent()?.kind() == JsSyntaxKind::JS_FOR_STATEMENT
+        {
+            return None;
+        }
+
+        let mut bindings = Vec::new();
+
+        for declarator in declaration.declarators() {
+            let declarator = declarator.ok()?;
+            declarator.initializer()?;
+
+            for binding in declarator
+                .id()
+                .ok()?
+                .syntax()
+                .descendants()
+                .filter_map(JsIdentifierBinding::cast)
+            {
+                if binding.all_writes(model).next().is_some() {
+                    return None;
+                }
+
+                bindings.push(binding);
+            }
+        }
+
+        (!bindings.is_empty()).then_some(bindings)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let kind = ctx.query().kind().ok()?;
+        let names = binding_names(state);
+        let verb = if state.len() == 1 { "is" } else { "are" };
+
+        Some(RuleDiagnostic::new(
+            rule_category!(),
+            kind.text_trimmed_range(),
+            markup! {
+                <Emphasis>{names}</Emphasis>" "{verb}" never reassigned, use "<Emphasis>"const"</Emphasis>" instead of "<Emphasis>"let"</Emphasis>"."
+            },
+        ))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let kind = ctx.query().kind().ok()?;
+        let names = binding_names(state);
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token(kind, make::token(T![const]));
+
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            // The semantic model doesn't see the reassignments made through `eval`
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Use "<Emphasis>"const"</Emphasis>" to declare "<Emphasis>{names}</Emphasis>"." }
+                .to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns the comma separated names of `bindings`
+fn binding_names(bindings: &[JsIdentifierBinding]) -> String {
+    bindings
+        .iter()
+        .filter_map(|binding| binding.name_token().ok())
+        .map(|name| name.text_trimmed().to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,149 @@
+use rome_analyze::SuppressionHook;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    JsLanguage, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken, JsxAnyChild, JsxChildList, JsxText,
+    TextRange, TextSize, TriviaPieceKind, T,
+};
+use rome_rowan::{AstNode, AstNodeList, BatchMutation};
+
+/// Returns the [SuppressionHook] of the JavaScript analyzer
+pub(crate) fn suppression_hook() -> SuppressionHook<JsLanguage> {
+    SuppressionHook {
+        block_suppression: jsx_suppression_mutation,
+        requires_block_comments: is_in_single_line_construct,
+    }
+}
+
+/// Returns `true` if the code at `offset` is inside of an object literal, an array literal, or
+/// the body of an arrow function that starts on the same line. The suppression of such a line
+/// uses `/* */` block comments, which remain valid if the construct is collapsed with the
+/// line above:
+///
+/// ```js
+/// /* rome-ignore lint(group/rule): <explanation> */
+/// const a = { b: a == b };
+/// ```
+fn is_in_single_line_construct(root: &JsSyntaxNode, offset: TextSize) -> bool {
+    let token = match root.token_at_offset(offset).right_biased() {
+        Some(token) => token,
+        None => return false,
+    };
+
+    for node in token.ancestors() {
+        let starts_line = node.first_token().map_or(false, |token| {
+            token
+                .leading_trivia()
+                .pieces()
+                .any(|piece| piece.is_newline())
+        });
+
+        if starts_line {
+            return false;
+        }
+
+        if matches!(
+            node.kind(),
+            JsSyntaxKind::JS_OBJECT_EXPRESSION
+                | JsSyntaxKind::JS_ARRAY_EXPRESSION
+                | JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION
+        ) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Inserts the suppression `comments` as `{/* comment */}` expression children if the line
+/// containing `offset` starts inside of JSX children, where a line comment would be printed as text:
+///
+/// ```jsx
+/// <div>
+///     {/* rome-ignore lint(group/rule): <explanation> */}
+///     <a onClick={a == b} />
+/// </div>
+/// ```
+///
+/// Returns `None` if the line starts with a token that can hold a line comment in its leading trivia.
+fn jsx_suppression_mutation(
+    root: &JsSyntaxNode,
+    offset: TextSize,
+    comments: &[String],
+) -> Option<(BatchMutation<JsLanguage>, TextRange)> {
+    let mut token = root.token_at_offset(offset).right_biased()?;
+
+    // Find the JSX text the line starts in, if any
+    let text = loop {
+        if token
+            .leading_trivia()
+            .pieces()
+            .any(|piece| piece.is_newline())
+        {
+            return None;
+        }
+
+        token = token.prev_token()?;
+
+        if token.kind() == JsSyntaxKind::JSX_TEXT_LITERAL && token.text().contains('\n') {
+            break JsxText::cast(token.parent()?)?;
+        }
+    };
+
+    let children = JsxChildList::cast(text.syntax().parent()?)?;
+
+    let value_token = text.value_token().ok()?;
+    let value = value_token.text();
+    let line_start = value.rfind('\n')? + 1;
+    let (before, line) = value.split_at(line_start);
+    let content_start = line.len() - line.trim_start().len();
+    let (indentation, content) = line.split_at(content_start);
+    // The comments are inserted after the indentation of the line
+    let range = TextRange::empty(
+        value_token.text_range().start() + TextSize::of(before) + TextSize::of(indentation),
+    );
+    let newline = if before.ends_with("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+
+    let mut suppression = vec![jsx_text(&format!("{before}{indentation}"))];
+    for (index, comment) in comments.iter().enumerate() {
+        let comment = format!("/* {comment} */");
+        let l_curly = make::token(T!['{'])
+            .with_trailing_trivia([(TriviaPieceKind::MultiLineComment, comment.as_str())]);
+
+        suppression.push(JsxAnyChild::from(
+            make::jsx_expression_child(l_curly, make::token(T!['}'])).build(),
+        ));
+
+        let text = if index + 1 == comments.len() {
+            format!("{newline}{indentation}{content}")
+        } else {
+            format!("{newline}{indentation}")
+        };
+        suppression.push(jsx_text(&text));
+    }
+
+    let mut next_children = Vec::new();
+    for child in children.iter() {
+        if child.syntax() == text.syntax() {
+            next_children.append(&mut suppression);
+        } else {
+            next_children.push(child);
+        }
+    }
+
+    let mut mutation = BatchMutation::new(root.clone());
+    mutation.replace_node(children, make::jsx_child_list(next_children));
+    Some((mutation, range))
+}
+
+fn jsx_text(text: &str) -> JsxAnyChild {
+    JsxAnyChild::from(make::jsx_text(JsSyntaxToken::new_detached(
+        JsSyntaxKind::JSX_TEXT_LITERAL,
+        text,
+        [],
+        [],
+    )))
+}
@@ -0,0 +1,15 @@
+const a = [1, 2];
+const b = { c: 1 } // no semicolon
+const d = [1], e = { f: 2 };
+
+// already asserted
+const g = [1] as number[];
+const h = <const>{ i: 1 };
+
+// not a literal
+const j = k;
+
+// annotated
+const l: number[] = [1];
+
+let m = [1];
@@ -0,0 +1,22 @@
+const a = 1; // first
+const b = 2, c = 3; // second
+
+let d = 4
+let e = 5 // no semicolon
+
+function f() {
+	let g;
+	let { h } = i;
+}
+
+// can't merge different keywords
+const j = 1;
+let k = 2;
+
+// can't merge over a comment
+let l = 1;
+// comment
+let m = 2;
+
+var n = 1;
+var o = 2;
@@ -0,0 +1,8 @@
+let a;
+let b = 1, c;
+export let d;
+let { e };
+
+function f() {
+    let g;
+}
@@ -0,0 +1,8 @@
+const a = 1;
+let b = undefined;
+var c;
+
+for (let i = 0; i < 10; i++) {}
+for (let i; ; ) {}
+for (const j of list) {}
+for (let k in object) {}
@@ -0,0 +1,9 @@
+declare const a: number;
+
+declare namespace N {
+    const b: string;
+}
+
+declare module "m" {
+    let c: boolean;
+}
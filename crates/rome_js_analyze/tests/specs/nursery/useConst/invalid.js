@@ -0,0 +1,15 @@
+let a = 1;
+console.log(a);
+
+let b = 2, c = 3;
+
+let { d, e: [f, ...g], ...h } = object;
+
+/* leading */ let i = 4; // trailing
+
+function j() {
+    let k = 5;
+    return () => k;
+}
+
+export let l = 6;
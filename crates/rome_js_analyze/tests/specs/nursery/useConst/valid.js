@@ -0,0 +1,27 @@
+let a = 1;
+a = 2;
+
+let b = 2, c = 3;
+c++;
+
+let { d, e: [f] } = object;
+[f] = other;
+
+let g = 4;
+function h() {
+    g += 1;
+}
+
+let i = () => {
+    i = null;
+};
+
+let j;
+let k = 5, l;
+
+for (let m = 0; m < 10; m++) {}
+for (let n = 0; ; ) {}
+for (let o of list) {}
+
+const p = 6;
+var q = 7;
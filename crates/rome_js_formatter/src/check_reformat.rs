@@ -5,6 +5,7 @@ use rome_diagnostics::v2::{DiagnosticExt, PrintDiagnostic};
 use rome_diagnostics::{file::FileId, termcolor};
 use rome_js_parser::parse;
 use rome_js_syntax::{JsSyntaxNode, SourceType};
+use rome_rowan::Direction;
 
 pub struct CheckReformatParams<'a> {
     pub root: &'a JsSyntaxNode,
@@ -71,3 +72,53 @@ pub fn check_reformat(params: CheckReformatParams) {
         similar_asserts::assert_eq!(text, printed.as_code());
     }
 }
+
+/// Formats `source` twice and asserts that the comments survive the formatting:
+/// the second pass must print the output of the first one unchanged, and the comments
+/// of the output must contain the same non-whitespace characters as the comments of `source`.
+pub fn assert_comment_reflow_stable(source: &str, options: JsFormatOptions) {
+    let source_type = options.source_type();
+    let parsed = parse(source, FileId::zero(), source_type);
+
+    let formatted = format_node(options.clone(), &parsed.syntax())
+        .unwrap()
+        .print()
+        .unwrap();
+    let reparsed = parse(formatted.as_code(), FileId::zero(), source_type);
+    let reformatted = format_node(options, &reparsed.syntax())
+        .unwrap()
+        .print()
+        .unwrap();
+
+    similar_asserts::assert_eq!(
+        formatted.as_code(),
+        reformatted.as_code(),
+        "formatting the output again changed it"
+    );
+
+    similar_asserts::assert_eq!(
+        comments_content(&parsed.syntax()),
+        comments_content(&reparsed.syntax()),
+        "formatting changed the content of the comments"
+    );
+}
+
+/// Returns the non-whitespace characters of the comments of `root`, in source order
+fn comments_content(root: &JsSyntaxNode) -> String {
+    root.descendants_tokens(Direction::Next)
+        .flat_map(|token| {
+            token
+                .leading_trivia()
+                .pieces()
+                .chain(token.trailing_trivia().pieces())
+        })
+        .filter(|piece| piece.is_comments())
+        .flat_map(|piece| {
+            piece
+                .text()
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
@@ -7,14 +7,17 @@ use rome_formatter::{
     },
     write,
 };
-use rome_js_syntax::suppression::{parse_suppression_comment, SuppressionCategory};
+use rome_js_syntax::suppression::{
+    parse_suppression_comment, SuppressionCategory, SuppressionScope,
+};
 use rome_js_syntax::{
     JsAnyClass, JsAnyName, JsAnyRoot, JsAnyStatement, JsArrayHole, JsArrowFunctionExpression,
     JsBlockStatement, JsCallArguments, JsCatchClause, JsEmptyStatement, JsFinallyClause,
     JsFormalParameter, JsFunctionBody, JsIdentifierExpression, JsIfStatement, JsLanguage,
     JsSyntaxKind, JsSyntaxNode, JsVariableDeclarator, JsWhileStatement, TsInterfaceDeclaration,
+    TsTypeAnnotation,
 };
-use rome_rowan::{AstNode, SyntaxNodeOptionExt, SyntaxTriviaPieceComments, TextLen};
+use rome_rowan::{AstNode, SyntaxNodeOptionExt, SyntaxTriviaPieceComments};
 
 pub type JsComments = Comments<JsLanguage>;
 
@@ -29,39 +32,7 @@ impl FormatRule<SourceComment<JsLanguage>> for FormatJsLeadingComment {
         comment: &SourceComment<JsLanguage>,
         f: &mut Formatter<Self::Context>,
     ) -> FormatResult<()> {
-        if is_doc_comment(comment.piece()) {
-            let mut source_offset = comment.piece().text_range().start();
-
-            let mut lines = comment.piece().text().lines();
-
-            // SAFETY: Safe, `is_doc_comment` only returns `true` for multiline comments
-            let first_line = lines.next().unwrap();
-            write!(f, [dynamic_text(first_line.trim_end(), source_offset)])?;
-
-            source_offset += first_line.text_len();
-
-            // Indent the remaining lines by one space so that all `*` are aligned.
-            write!(
-                f,
-                [align(
-                    1,
-                    &format_once(|f| {
-                        for line in lines {
-                            write!(
-                                f,
-                                [hard_line_break(), dynamic_text(line.trim(), source_offset)]
-                            )?;
-
-                            source_offset += line.text_len();
-                        }
-
-                        Ok(())
-                    })
-                )]
-            )
-        } else {
-            write!(f, [comment.piece().as_piece()])
-        }
+        write!(f, [format_aligned_block_comment(comment)])
     }
 }
 
@@ -127,6 +98,205 @@ pub fn is_doc_comment(comment: &SyntaxTriviaPieceComments<JsLanguage>) -> bool {
     })
 }
 
+/// Returns `true` if `comment` is a block comment framed by borders, like a license banner:
+/// its first and last lines are runs of the same character, and all other lines
+/// start and end with the same character at the same columns.
+///
+/// # Examples
+///
+/// ```
+/// # use rome_js_parser::parse_module;
+/// # use rome_js_syntax::JsLanguage;
+/// # use rome_rowan::{Direction, SyntaxTriviaPieceComments};
+/// # use rome_diagnostics::file::FileId;
+///  use rome_js_formatter::comments::is_framed_comment;
+///
+/// # fn parse_comment(source: &str) -> SyntaxTriviaPieceComments<JsLanguage> {
+/// #     let root = parse_module(source, FileId::zero()).tree();
+/// #     root
+/// #        .eof_token()
+/// #        .expect("Root to have an EOF token")
+/// #        .leading_trivia()
+/// #        .pieces()
+/// #        .filter_map(|piece| piece.as_comments())
+/// #        .next()
+/// #        .expect("Source to contain a comment.")
+/// # }
+///
+/// assert!(is_framed_comment(&parse_comment(r#"
+/// /*****************
+///  * Copyright     *
+///  * License: MIT  *
+///  *****************/
+/// "#)));
+///
+/// // Doc comments aren't framed
+/// assert!(!is_framed_comment(&parse_comment(r#"
+/// /**
+///  * Multiline doc comment
+///  */
+/// "#)));
+///
+/// // The right border isn't aligned
+/// assert!(!is_framed_comment(&parse_comment(r#"
+/// /*****************
+///  * Copyright *
+///  * License: MIT  *
+///  *****************/
+/// "#)));
+/// ```
+pub fn is_framed_comment(comment: &SyntaxTriviaPieceComments<JsLanguage>) -> bool {
+    if !comment.has_newline() {
+        return false;
+    }
+
+    let lines: Vec<_> = comment.text().lines().map(str::trim_end).collect();
+
+    let (first, interior, last) = match lines.as_slice() {
+        [first, interior @ .., last] if !interior.is_empty() => (first, interior, last),
+        _ => return false,
+    };
+
+    // The top and bottom borders repeat the same character at least three times
+    let is_border = |border: Option<&str>| match border {
+        Some(border) if border.len() >= 3 => {
+            let mut chars = border.chars();
+            chars.next().map_or(false, |c| {
+                c.is_ascii_punctuation() && chars.all(|other| other == c)
+            })
+        }
+        _ => false,
+    };
+
+    if !is_border(first.strip_prefix("/*")) || !is_border(last.trim_start().strip_suffix("*/")) {
+        return false;
+    }
+
+    let first_interior = interior[0].trim_start();
+    let side = match first_interior.chars().next() {
+        Some(side) if side.is_ascii_punctuation() => side,
+        _ => return false,
+    };
+    let indent = interior[0].len() - first_interior.len();
+    let width = interior[0].chars().count();
+
+    interior.iter().all(|line| {
+        let content = line.trim_start();
+
+        line.len() - content.len() == indent
+            && line.chars().count() == width
+            && content.len() >= 2
+            && content.starts_with(side)
+            && content.ends_with(side)
+    })
+}
+
+/// Returns `true` if `comment` is a directive for another tool, like a TypeScript or ESLint pragma,
+/// a bundler annotation, or a triple-slash directive.
+///
+/// # Examples
+///
+/// ```
+/// # use rome_js_parser::parse_module;
+/// # use rome_js_syntax::JsLanguage;
+/// # use rome_rowan::{Direction, SyntaxTriviaPieceComments};
+/// # use rome_diagnostics::file::FileId;
+///  use rome_js_formatter::comments::is_directive_comment;
+///
+/// # fn parse_comment(source: &str) -> SyntaxTriviaPieceComments<JsLanguage> {
+/// #     let root = parse_module(source, FileId::zero()).tree();
+/// #     root
+/// #        .eof_token()
+/// #        .expect("Root to have an EOF token")
+/// #        .leading_trivia()
+/// #        .pieces()
+/// #        .filter_map(|piece| piece.as_comments())
+/// #        .next()
+/// #        .expect("Source to contain a comment.")
+/// # }
+///
+/// assert!(is_directive_comment(&parse_comment("// @ts-ignore")));
+/// assert!(is_directive_comment(&parse_comment("// eslint-disable-next-line no-console")));
+/// assert!(is_directive_comment(&parse_comment("/* @__PURE__ */")));
+/// assert!(is_directive_comment(&parse_comment("/** @jsx h */")));
+/// assert!(is_directive_comment(&parse_comment(r#"/// <reference path="a.d.ts" />"#)));
+///
+/// assert!(!is_directive_comment(&parse_comment("// a regular comment")));
+/// assert!(!is_directive_comment(&parse_comment("/* see @__PURE__ */")));
+/// ```
+pub fn is_directive_comment(comment: &SyntaxTriviaPieceComments<JsLanguage>) -> bool {
+    const DIRECTIVES: [&str; 10] = [
+        "@ts-",
+        "eslint-",
+        "eslint ",
+        "@__",
+        "#__",
+        "istanbul ignore",
+        "c8 ignore",
+        "@jsx",
+        "@flow",
+        "<reference ",
+    ];
+
+    let text = comment.text();
+    let content = match text.strip_prefix("/*") {
+        Some(block) => block.strip_suffix("*/").unwrap_or(block),
+        None => text.trim_start_matches('/'),
+    };
+    let content = content.trim_start_matches('*').trim_start();
+
+    DIRECTIVES
+        .iter()
+        .any(|directive| content.starts_with(directive))
+}
+
+/// Returns `true` if `comment` is a legal comment that minifiers retain: a comment starting with
+/// `/*!` or `//!`, or a comment containing `@license` or `@preserve`.
+///
+/// # Examples
+///
+/// ```
+/// # use rome_js_parser::parse_module;
+/// # use rome_js_syntax::JsLanguage;
+/// # use rome_rowan::{Direction, SyntaxTriviaPieceComments};
+/// # use rome_diagnostics::file::FileId;
+///  use rome_js_formatter::comments::is_legal_comment;
+///
+/// # fn parse_comment(source: &str) -> SyntaxTriviaPieceComments<JsLanguage> {
+/// #     let root = parse_module(source, FileId::zero()).tree();
+/// #     root
+/// #        .eof_token()
+/// #        .expect("Root to have an EOF token")
+/// #        .leading_trivia()
+/// #        .pieces()
+/// #        .filter_map(|piece| piece.as_comments())
+/// #        .next()
+/// #        .expect("Source to contain a comment.")
+/// # }
+///
+/// assert!(is_legal_comment(&parse_comment("/*! lib v1.0 | MIT */")));
+/// assert!(is_legal_comment(&parse_comment("//! lib v1.0")));
+/// assert!(is_legal_comment(&parse_comment("/**\n * @license MIT\n */")));
+/// assert!(is_legal_comment(&parse_comment("// @preserve")));
+///
+/// assert!(!is_legal_comment(&parse_comment("/* a regular comment */")));
+/// assert!(!is_legal_comment(&parse_comment("/** @licensed */")));
+/// ```
+pub fn is_legal_comment(comment: &SyntaxTriviaPieceComments<JsLanguage>) -> bool {
+    let text = comment.text();
+
+    if text.starts_with("/*!") || text.starts_with("//!") {
+        return true;
+    }
+
+    ["@license", "@preserve"].iter().any(|marker| {
+        text.match_indices(marker).any(|(index, _)| {
+            !text[index + marker.len()..]
+                .starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_')
+        })
+    })
+}
+
 #[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
 pub struct JsCommentStyle;
 
@@ -134,7 +304,10 @@ impl CommentStyle for JsCommentStyle {
     type Language = JsLanguage;
 
     fn is_suppression(text: &str) -> bool {
+        // The formatter doesn't support suppressing the formatting of the whole file,
+        // `rome-ignore-all format` comments are formatted like any other comment
         parse_suppression_comment(text)
+            .filter(|suppression| suppression.scope == SuppressionScope::Line)
             .flat_map(|suppression| suppression.categories)
             .any(|(category, _)| category == SuppressionCategory::Format)
     }
@@ -151,6 +324,18 @@ impl CommentStyle for JsCommentStyle {
         }
     }
 
+    fn is_framed_block(comment: &SyntaxTriviaPieceComments<Self::Language>) -> bool {
+        is_framed_comment(comment)
+    }
+
+    fn is_directive(comment: &SyntaxTriviaPieceComments<Self::Language>) -> bool {
+        is_directive_comment(comment)
+    }
+
+    fn is_legal_comment(comment: &SyntaxTriviaPieceComments<Self::Language>) -> bool {
+        is_legal_comment(comment)
+    }
+
     fn place_comment(
         &self,
         comment: DecoratedComment<Self::Language>,
@@ -167,7 +352,9 @@ impl CommentStyle for JsCommentStyle {
                 .or_else(handle_for_comment)
                 .or_else(handle_root_comments)
                 .or_else(handle_array_hole_comment)
+                .or_else(handle_variable_declaration_comment)
                 .or_else(handle_variable_declarator_comment)
+                .or_else(handle_type_annotation_comment)
                 .or_else(handle_parameter_comment)
                 .or_else(handle_labelled_statement_comment)
                 .or_else(handle_call_expression_comment)
@@ -184,6 +371,7 @@ impl CommentStyle for JsCommentStyle {
                 .or_else(handle_method_comment)
                 .or_else(handle_for_comment)
                 .or_else(handle_root_comments)
+                .or_else(handle_type_annotation_comment)
                 .or_else(handle_parameter_comment)
                 .or_else(handle_array_hole_comment)
                 .or_else(handle_labelled_statement_comment)
@@ -200,6 +388,7 @@ impl CommentStyle for JsCommentStyle {
                 .or_else(handle_array_hole_comment)
                 .or_else(handle_call_expression_comment)
                 .or_else(handle_continue_break_comment)
+                .or_else(handle_variable_declaration_comment)
                 .or_else(handle_class_comment),
         }
     }
@@ -910,6 +1099,34 @@ fn handle_for_comment(comment: DecoratedComment<JsLanguage>) -> CommentPlacement
     }
 }
 
+/// Makes the comments between the declaration keyword and the first declarator dangling comments
+/// of the declaration so that they remain next to the keyword:
+///
+/// ```javascript
+/// const /* type */ a = 1;
+/// let // comment
+///     b = 2;
+/// for (const /* type */ c of list) {}
+/// ```
+///
+/// Comments on their own line and suppression comments remain leading comments of the declarator.
+fn handle_variable_declaration_comment(
+    comment: DecoratedComment<JsLanguage>,
+) -> CommentPlacement<JsLanguage> {
+    let enclosing = comment.enclosing_node();
+
+    if matches!(
+        enclosing.kind(),
+        JsSyntaxKind::JS_VARIABLE_DECLARATION | JsSyntaxKind::JS_FOR_VARIABLE_DECLARATION
+    ) && comment.preceding_node().is_none()
+        && !JsCommentStyle::is_suppression(comment.piece().text())
+    {
+        CommentPlacement::dangling(enclosing.clone(), comment)
+    } else {
+        CommentPlacement::Default(comment)
+    }
+}
+
 fn handle_variable_declarator_comment(
     comment: DecoratedComment<JsLanguage>,
 ) -> CommentPlacement<JsLanguage> {
@@ -1000,6 +1217,45 @@ fn handle_variable_declarator_comment(
     CommentPlacement::Default(comment)
 }
 
+/// Makes the line comments ending the line before the colon of the type annotation of a variable
+/// declarator leading comments of the type, so that they stay next to the colon instead of being
+/// printed after the value of the declarator.
+///
+/// ```javascript
+/// const a // comment
+///     : string = "test";
+/// ```
+///
+/// Is formatted as:
+///
+/// ```javascript
+/// const a: // comment
+///     string = "test";
+/// ```
+///
+/// Comments on their own line remain leading comments of the type annotation, which keeps them
+/// before the colon. Block comments on the line of the colon, like in `const a /* comment */: string`,
+/// are trailing comments of the id with the default placement and are left where they are.
+fn handle_type_annotation_comment(
+    comment: DecoratedComment<JsLanguage>,
+) -> CommentPlacement<JsLanguage> {
+    if comment.enclosing_node().kind() != JsSyntaxKind::JS_VARIABLE_DECLARATOR
+        || !comment.kind().is_line()
+        || !comment.text_position().is_end_of_line()
+    {
+        return CommentPlacement::Default(comment);
+    }
+
+    match comment
+        .following_node()
+        .and_then(TsTypeAnnotation::cast_ref)
+        .and_then(|annotation| annotation.ty().ok())
+    {
+        Some(ty) => CommentPlacement::leading(ty.into_syntax(), comment),
+        None => CommentPlacement::Default(comment),
+    }
+}
+
 fn handle_parameter_comment(comment: DecoratedComment<JsLanguage>) -> CommentPlacement<JsLanguage> {
     // Make all own line comments leading comments of the parameter
     // ```javascript
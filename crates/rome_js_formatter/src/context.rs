@@ -1,11 +1,16 @@
 use crate::comments::{FormatJsLeadingComment, JsCommentStyle, JsComments};
 use crate::context::trailing_comma::TrailingComma;
+use crate::utils::number_utils::format_trimmed_number;
 use rome_formatter::printer::PrinterOptions;
 use rome_formatter::{
-    CstFormatContext, FormatContext, FormatElement, FormatOptions, IndentStyle, LineWidth,
+    CstFormatContext, FormatContext, FormatElement, FormatOptions, GroupId, IndentStyle, LineWidth,
     TransformSourceMap,
 };
-use rome_js_syntax::{JsAnyFunctionBody, JsLanguage, SourceType};
+use rome_js_syntax::{
+    JsAnyFunctionBody, JsLanguage, JsSyntaxKind, JsSyntaxToken, JsVariableDeclarationClause,
+    SourceType,
+};
+use std::borrow::Cow;
 use std::fmt;
 use std::fmt::Debug;
 use std::rc::Rc;
@@ -42,6 +47,13 @@ pub struct JsFormatContext {
     /// This should be rare enough for us not to care about it.
     cached_function_body: Option<(JsAnyFunctionBody, FormatElement)>,
 
+    /// Stores the layout of the last formatted variable declaration clause.
+    ///
+    /// The formatting of the statements doesn't depend on it. It's set for the
+    /// [rome_formatter::Format] implementations that write a declaration clause and want to know
+    /// whether it breaks, see [JsFormatContext::declaration_clause_layout].
+    declaration_clause_layout: Option<(JsVariableDeclarationClause, DeclarationClauseLayout)>,
+
     source_map: Option<TransformSourceMap>,
 }
 
@@ -51,6 +63,7 @@ impl JsFormatContext {
             options,
             comments: Rc::new(comments),
             cached_function_body: None,
+            declaration_clause_layout: None,
             source_map: None,
         }
     }
@@ -85,12 +98,71 @@ impl JsFormatContext {
         self.cached_function_body = Some((body.clone(), formatted))
     }
 
+    /// Returns the layout of `clause` if it is the last formatted variable declaration clause,
+    /// or `None` if another clause has been formatted since.
+    pub fn declaration_clause_layout(
+        &self,
+        clause: &JsVariableDeclarationClause,
+    ) -> Option<DeclarationClauseLayout> {
+        self.declaration_clause_layout
+            .as_ref()
+            .and_then(|(formatted, layout)| (formatted == clause).then_some(*layout))
+    }
+
+    /// Sets the layout of the last formatted variable declaration clause.
+    pub(crate) fn set_declaration_clause_layout(
+        &mut self,
+        clause: &JsVariableDeclarationClause,
+        layout: DeclarationClauseLayout,
+    ) {
+        self.declaration_clause_layout = Some((clause.clone(), layout))
+    }
+
     pub fn with_source_map(mut self, source_map: Option<TransformSourceMap>) -> Self {
         self.source_map = source_map;
         self
     }
 }
 
+/// The layout of a formatted variable declaration clause.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct DeclarationClauseLayout {
+    /// The id of the group of the declaration. Pass it to [rome_formatter::builders::if_group_breaks]
+    /// for content depending on whether the printer breaks the declaration across multiple lines.
+    pub group_id: GroupId,
+
+    /// Whether the declaration contains a hard line break, for example in a function body,
+    /// so that it breaks across multiple lines whatever the line width. A declaration that
+    /// only breaks because it exceeds the line width has no hard break, use the `group_id`
+    /// to handle this case.
+    pub has_hard_break: bool,
+}
+
+/// The spacing before the `=` separating a variable declarator from its initializer,
+/// for example to align the `=` of consecutive declarations.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct AssignmentSpacing {
+    before: u8,
+}
+
+impl AssignmentSpacing {
+    /// Creates a spacing with `before` spaces between the declared binding and the `=`
+    pub const fn new(before: u8) -> Self {
+        Self { before }
+    }
+
+    /// The number of spaces between the declared binding and the `=`. Defaults to 1.
+    pub const fn before(&self) -> u8 {
+        self.before
+    }
+}
+
+impl Default for AssignmentSpacing {
+    fn default() -> Self {
+        Self { before: 1 }
+    }
+}
+
 #[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
 pub struct TabWidth(u8);
 
@@ -126,6 +198,33 @@ impl CstFormatContext for JsFormatContext {
     fn comments(&self) -> &JsComments {
         &self.comments
     }
+
+    fn is_strict_comments(&self) -> bool {
+        self.options.strict_comments
+    }
+
+    fn max_empty_lines(&self) -> u8 {
+        self.options.max_empty_lines
+    }
+
+    fn dangling_comments_force_break(&self) -> bool {
+        self.options.dangling_comments_force_break
+    }
+
+    fn trailing_comment_leading_spaces(&self) -> usize {
+        self.options.trailing_comment_leading_spaces
+    }
+
+    fn normalize_token_text<'a>(&self, token: &'a JsSyntaxToken) -> Option<Cow<'a, str>> {
+        match token.kind() {
+            // Number literals formatted as is, for example the key of `{ 0XFF: 1 }`
+            JsSyntaxKind::JS_NUMBER_LITERAL => match format_trimmed_number(token.text_trimmed()) {
+                Cow::Borrowed(_) => None,
+                normalized => Some(normalized),
+            },
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -145,6 +244,36 @@ pub struct JsFormatOptions {
     /// Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "all".
     trailing_comma: TrailingComma,
 
+    /// Whether statements end with a semicolon. Defaults to "always".
+    semicolons: Semicolons,
+
+    /// Whether to remove the empty lines following the opening brace and preceding the closing
+    /// brace of a block. Defaults to `true`.
+    trim_block_edges: bool,
+
+    /// Whether formatting fails in debug builds if the formatter drops some comments. Defaults to `true`.
+    /// Otherwise, the dropped comments are available through [rome_formatter::Formatted::dropped_comments].
+    strict_comments: bool,
+
+    /// The maximum number of consecutive empty lines preserved around comments. Defaults to 1.
+    max_empty_lines: u8,
+
+    /// Whether the dangling block comments of a node force it to break, like `[\n\t/* comment */\n]`.
+    /// Defaults to `false`.
+    dangling_comments_force_break: bool,
+
+    /// The number of spaces between a trailing comment ending its line and the code it trails.
+    /// Defaults to 1.
+    trailing_comment_leading_spaces: usize,
+
+    /// The spacing before the `=` separating a variable declarator from its initializer.
+    assignment_spacing: AssignmentSpacing,
+
+    /// The maximum nesting depth of the formatted nodes. Formatting fails with
+    /// [rome_formatter::FormatError::DepthExceeded] for deeper trees instead of overflowing the stack.
+    /// Defaults to 512.
+    max_depth: u32,
+
     /// Information related to the current file
     source_type: SourceType,
 }
@@ -158,6 +287,14 @@ impl JsFormatOptions {
             quote_style: QuoteStyle::default(),
             quote_properties: QuoteProperties::default(),
             trailing_comma: TrailingComma::default(),
+            semicolons: Semicolons::default(),
+            trim_block_edges: true,
+            strict_comments: true,
+            max_empty_lines: 1,
+            dangling_comments_force_break: false,
+            trailing_comment_leading_spaces: 1,
+            assignment_spacing: AssignmentSpacing::default(),
+            max_depth: 512,
         }
     }
 
@@ -186,6 +323,46 @@ impl JsFormatOptions {
         self
     }
 
+    pub fn with_semicolons(mut self, semicolons: Semicolons) -> Self {
+        self.semicolons = semicolons;
+        self
+    }
+
+    pub fn with_trim_block_edges(mut self, trim_block_edges: bool) -> Self {
+        self.trim_block_edges = trim_block_edges;
+        self
+    }
+
+    pub fn with_strict_comments(mut self, strict_comments: bool) -> Self {
+        self.strict_comments = strict_comments;
+        self
+    }
+
+    pub fn with_max_empty_lines(mut self, max_empty_lines: u8) -> Self {
+        self.max_empty_lines = max_empty_lines;
+        self
+    }
+
+    pub fn with_dangling_comments_force_break(mut self, force_break: bool) -> Self {
+        self.dangling_comments_force_break = force_break;
+        self
+    }
+
+    pub fn with_trailing_comment_leading_spaces(mut self, spaces: usize) -> Self {
+        self.trailing_comment_leading_spaces = spaces;
+        self
+    }
+
+    pub fn with_assignment_spacing(mut self, assignment_spacing: AssignmentSpacing) -> Self {
+        self.assignment_spacing = assignment_spacing;
+        self
+    }
+
+    pub fn with_max_depth(mut self, max_depth: u32) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
     pub fn quote_style(&self) -> QuoteStyle {
         self.quote_style
     }
@@ -202,6 +379,38 @@ impl JsFormatOptions {
         self.trailing_comma
     }
 
+    pub fn semicolons(&self) -> Semicolons {
+        self.semicolons
+    }
+
+    pub fn trim_block_edges(&self) -> bool {
+        self.trim_block_edges
+    }
+
+    pub fn strict_comments(&self) -> bool {
+        self.strict_comments
+    }
+
+    pub fn max_empty_lines(&self) -> u8 {
+        self.max_empty_lines
+    }
+
+    pub fn dangling_comments_force_break(&self) -> bool {
+        self.dangling_comments_force_break
+    }
+
+    pub fn trailing_comment_leading_spaces(&self) -> usize {
+        self.trailing_comment_leading_spaces
+    }
+
+    pub fn assignment_spacing(&self) -> AssignmentSpacing {
+        self.assignment_spacing
+    }
+
+    pub fn max_depth(&self) -> u32 {
+        self.max_depth
+    }
+
     pub fn tab_width(&self) -> TabWidth {
         match self.indent_style {
             IndentStyle::Tab => 2.into(),
@@ -232,7 +441,49 @@ impl fmt::Display for JsFormatOptions {
         writeln!(f, "Line width: {}", self.line_width.value())?;
         writeln!(f, "Quote style: {}", self.quote_style)?;
         writeln!(f, "Quote properties: {}", self.quote_properties)?;
-        writeln!(f, "Trailing comma: {}", self.trailing_comma)
+        writeln!(f, "Trailing comma: {}", self.trailing_comma)?;
+
+        // The options below are only printed when they differ from their default,
+        // so the snapshots of the files formatted with the defaults don't list them
+        let default = JsFormatOptions::new(self.source_type);
+
+        if self.semicolons != default.semicolons {
+            writeln!(f, "Semicolons: {}", self.semicolons)?;
+        }
+
+        if self.trim_block_edges != default.trim_block_edges {
+            writeln!(f, "Trim block edges: {}", self.trim_block_edges)?;
+        }
+
+        if self.max_empty_lines != default.max_empty_lines {
+            writeln!(f, "Max empty lines: {}", self.max_empty_lines)?;
+        }
+
+        if self.dangling_comments_force_break != default.dangling_comments_force_break {
+            writeln!(
+                f,
+                "Dangling comments force break: {}",
+                self.dangling_comments_force_break
+            )?;
+        }
+
+        if self.trailing_comment_leading_spaces != default.trailing_comment_leading_spaces {
+            writeln!(
+                f,
+                "Trailing comment leading spaces: {}",
+                self.trailing_comment_leading_spaces
+            )?;
+        }
+
+        if self.assignment_spacing != default.assignment_spacing {
+            writeln!(
+                f,
+                "Assignment spacing: {}",
+                self.assignment_spacing.before()
+            )?;
+        }
+
+        Ok(())
     }
 }
 
@@ -351,3 +602,49 @@ impl fmt::Display for QuoteProperties {
         }
     }
 }
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema)
+)]
+pub enum Semicolons {
+    /// Print a semicolon at the end of every statement.
+    #[default]
+    Always,
+    /// Only print the semicolons that are required to keep the meaning of the program,
+    /// relying on automatic semicolon insertion otherwise.
+    AsNeeded,
+}
+
+impl Semicolons {
+    pub const fn is_always(&self) -> bool {
+        matches!(self, Semicolons::Always)
+    }
+
+    pub const fn is_as_needed(&self) -> bool {
+        matches!(self, Semicolons::AsNeeded)
+    }
+}
+
+impl FromStr for Semicolons {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "always" | "Always" => Ok(Self::Always),
+            "as-needed" | "AsNeeded" => Ok(Self::AsNeeded),
+            // TODO: replace this error with a diagnostic
+            _ => Err("Value not supported for Semicolons"),
+        }
+    }
+}
+
+impl fmt::Display for Semicolons {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Semicolons::Always => write!(f, "Always"),
+            Semicolons::AsNeeded => write!(f, "As needed"),
+        }
+    }
+}
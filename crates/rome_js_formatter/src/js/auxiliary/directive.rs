@@ -1,5 +1,7 @@
 use crate::prelude::*;
-use crate::utils::{FormatLiteralStringToken, FormatWithSemicolon, StringLiteralParentKind};
+use crate::utils::{
+    FormatLiteralStringToken, FormatWithStatementSemicolon, StringLiteralParentKind,
+};
 use rome_formatter::write;
 
 use rome_js_syntax::JsDirective;
@@ -17,9 +19,10 @@ impl FormatNodeRule<JsDirective> for FormatJsDirective {
 
         write!(
             f,
-            [FormatWithSemicolon::new(
+            [FormatWithStatementSemicolon::new(
                 &FormatLiteralStringToken::new(&value_token?, StringLiteralParentKind::Directive),
-                semicolon_token.as_ref()
+                semicolon_token.as_ref(),
+                node.syntax()
             )]
         )
     }
@@ -23,7 +23,9 @@ impl FormatNodeRule<JsFunctionBody> for FormatJsFunctionBody {
                 f,
                 [
                     l_curly_token.format(),
-                    format_dangling_comments(node.syntax()).with_block_indent(),
+                    format_dangling_comments(node.syntax())
+                        .with_block_indent()
+                        .group_if_single_block(),
                     r_curly_token.format()
                 ]
             )
@@ -33,6 +33,9 @@ impl FormatNodeRule<JsModule> for FormatJsModule {
                 items.format(),
                 format_trailing_comments(node.syntax()),
                 format_removed(&eof_token?),
+                // Flushes the pending line suffix of a trailing line comment. The printer only
+                // writes a new line if the current line isn't empty, so a file ending with a
+                // line comment always ends with exactly one new line.
                 hard_line_break()
             ]
         )
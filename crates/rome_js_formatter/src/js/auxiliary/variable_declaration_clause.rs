@@ -1,5 +1,6 @@
+use crate::context::DeclarationClauseLayout;
 use crate::prelude::*;
-use crate::utils::FormatWithSemicolon;
+use crate::utils::FormatWithStatementSemicolon;
 
 use rome_js_syntax::JsVariableDeclarationClause;
 use rome_js_syntax::JsVariableDeclarationClauseFields;
@@ -18,6 +19,29 @@ impl FormatNodeRule<JsVariableDeclarationClause> for FormatJsVariableDeclaration
             semicolon_token,
         } = node.as_fields();
 
-        FormatWithSemicolon::new(&declaration.format(), semicolon_token.as_ref()).fmt(f)
+        let group_id = f.group_id("variable_declaration");
+        let declaration = declaration?;
+        let format_declaration = declaration.format().with_options(Some(group_id));
+
+        let mut recording = f.start_recording();
+        write!(
+            recording,
+            [FormatWithStatementSemicolon::new(
+                &format_declaration,
+                semicolon_token.as_ref(),
+                node.syntax(),
+            )]
+        )?;
+        let has_hard_break = recording.stop().will_break();
+
+        f.context_mut().set_declaration_clause_layout(
+            node,
+            DeclarationClauseLayout {
+                group_id,
+                has_hard_break,
+            },
+        );
+
+        Ok(())
     }
 }
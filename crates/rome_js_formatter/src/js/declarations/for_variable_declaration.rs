@@ -1,5 +1,7 @@
 use crate::prelude::*;
 
+use crate::js::declarations::variable_declaration::has_line_break_after_kind;
+use rome_formatter::trivia::FormatTrailingComments;
 use rome_formatter::{format_args, write};
 use rome_js_syntax::JsForVariableDeclaration;
 use rome_js_syntax::JsForVariableDeclarationFields;
@@ -14,13 +16,40 @@ impl FormatNodeRule<JsForVariableDeclaration> for FormatJsForVariableDeclaration
             declarator,
         } = node.as_fields();
 
+        let comments = f.context().comments().clone();
+
+        // The comments between the keyword and the declarator trail the keyword, like in
+        // variable declarations: `for (const /* type */ a of list)`
+        let format_declarator = format_with(|f| {
+            if has_line_break_after_kind(node.syntax(), &comments) {
+                write!(
+                    f,
+                    [indent(&format_args![
+                        hard_line_break(),
+                        declarator.format()
+                    ])]
+                )
+            } else {
+                write!(f, [space(), declarator.format()])
+            }
+        });
+
         write![
             f,
             [group(&format_args![
                 kind_token.format(),
-                space(),
-                declarator.format()
+                FormatTrailingComments::comments(comments.dangling_comments(node.syntax())),
+                format_declarator
             ])]
         ]
     }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &JsForVariableDeclaration,
+        _: &mut JsFormatter,
+    ) -> FormatResult<()> {
+        // Formatted inside of `fmt_fields`
+        Ok(())
+    }
 }
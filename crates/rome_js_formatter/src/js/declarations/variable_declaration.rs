@@ -1,23 +1,75 @@
 use crate::prelude::*;
-use rome_formatter::{format_args, write};
+use rome_formatter::trivia::FormatTrailingComments;
+use rome_formatter::{format_args, write, FormatRuleWithOptions, GroupId};
 
-use rome_js_syntax::JsVariableDeclaration;
 use rome_js_syntax::JsVariableDeclarationFields;
+use rome_js_syntax::{JsSyntaxNode, JsVariableDeclaration};
 
 #[derive(Debug, Clone, Default)]
-pub struct FormatJsVariableDeclaration;
+pub struct FormatJsVariableDeclaration {
+    group_id: Option<GroupId>,
+}
+
+impl FormatRuleWithOptions<JsVariableDeclaration> for FormatJsVariableDeclaration {
+    type Options = Option<GroupId>;
+
+    fn with_options(mut self, options: Self::Options) -> Self {
+        self.group_id = options;
+        self
+    }
+}
 
 impl FormatNodeRule<JsVariableDeclaration> for FormatJsVariableDeclaration {
     fn fmt_fields(&self, node: &JsVariableDeclaration, f: &mut JsFormatter) -> FormatResult<()> {
         let JsVariableDeclarationFields { kind, declarators } = node.as_fields();
 
+        let comments = f.context().comments().clone();
+
+        // The comments between the keyword and the first declarator trail the keyword:
+        // `const /* type */ a = 1`. The declarator list moves the first declarator to its own line
+        // if the last of these comments is followed by a line break.
+        let format_declarators = format_with(|f| {
+            if !has_line_break_after_kind(node.syntax(), &comments) {
+                write!(f, [space()])?;
+            }
+
+            write!(f, [declarators.format()])
+        });
+
         write!(
             f,
             [group(&format_args![
                 kind.format(),
-                space(),
-                declarators.format()
-            ])]
+                FormatTrailingComments::comments(comments.dangling_comments(node.syntax())),
+                format_declarators
+            ])
+            .with_group_id(self.group_id)]
         )
     }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &JsVariableDeclaration,
+        _: &mut JsFormatter,
+    ) -> FormatResult<()> {
+        // Formatted inside of `fmt_fields`
+        Ok(())
+    }
+}
+
+/// Returns `true` if the comments following the keyword of `declaration`, a variable declaration
+/// or the declaration of a `for...in` or `for...of` loop, end with a line comment
+/// or a block comment followed by a line break:
+///
+/// ```javascript
+/// let // comment
+///     a = 1;
+/// ```
+pub(crate) fn has_line_break_after_kind(declaration: &JsSyntaxNode, comments: &JsComments) -> bool {
+    comments
+        .dangling_comments(declaration)
+        .last()
+        .map_or(false, |comment| {
+            comment.kind().is_line() || comment.lines_after() > 0
+        })
 }
@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use rome_js_syntax::{JsAnyModuleItem, JsAnyStatement, JsModuleItemList};
+use rome_js_syntax::{JsAnyModuleItem, JsAnyStatement, JsModule, JsModuleItemList};
 
 #[derive(Debug, Clone, Default)]
 pub struct FormatJsModuleItemList;
@@ -10,6 +10,11 @@ impl FormatRule<JsModuleItemList> for FormatJsModuleItemList {
     fn fmt(&self, node: &JsModuleItemList, f: &mut JsFormatter) -> FormatResult<()> {
         let mut join = f.join_nodes_with_hardline();
 
+        // Allow printing the items of a module one by one when streaming the output
+        if node.parent::<JsModule>().is_some() {
+            join = join.flush_after_separators();
+        }
+
         for module_item in node {
             match module_item {
                 JsAnyModuleItem::JsAnyStatement(JsAnyStatement::JsEmptyStatement(empty)) => {
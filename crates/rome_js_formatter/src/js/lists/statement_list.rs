@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use rome_js_syntax::{JsAnyStatement, JsStatementList};
+use rome_js_syntax::{JsAnyStatement, JsScript, JsStatementList, JsSyntaxKind, T};
 
 #[derive(Debug, Clone, Default)]
 pub struct FormatJsStatementList;
@@ -8,15 +8,85 @@ impl FormatRule<JsStatementList> for FormatJsStatementList {
     type Context = JsFormatContext;
 
     fn fmt(&self, node: &JsStatementList, f: &mut JsFormatter) -> FormatResult<()> {
+        let trim_block_edges = f.options().trim_block_edges();
+        let comments = f.comments().clone();
+
+        // The statements directly enclosed by curly braces, unlike the statements of a script or a switch case.
+        // The statements of a function body follow its directives rather than the opening brace.
+        let is_block = node.syntax().parent().map_or(false, |parent| {
+            matches!(
+                parent.kind(),
+                JsSyntaxKind::JS_BLOCK_STATEMENT
+                    | JsSyntaxKind::JS_FUNCTION_BODY
+                    | JsSyntaxKind::JS_STATIC_INITIALIZATION_BLOCK_CLASS_MEMBER
+            )
+        });
+
         let mut join = f.join_nodes_with_hardline();
 
+        // Allow printing the statements of a script one by one when streaming the output
+        if node.parent::<JsScript>().is_some() {
+            join = join.flush_after_separators();
+        }
+
+        let mut last_statement = None;
+        let mut is_first = true;
+
         for statement in node.iter() {
             match statement {
                 JsAnyStatement::JsEmptyStatement(empty) => {
+                    // Empty statements only print their comments
+                    is_first = is_first && !comments.has_comments(empty.syntax());
                     join.entry_no_separator(&empty.format());
                 }
+                _ if is_first => {
+                    // Don't separate the first statement from the opening brace with an empty
+                    // line, even if it follows an empty statement.
+                    let keep_empty_line = !trim_block_edges
+                        && is_block
+                        && get_lines_before(statement.syntax()) > 1
+                        && !comments.has_leading_comments(statement.syntax());
+
+                    if keep_empty_line {
+                        join.entry_no_separator(&empty_line());
+                    }
+
+                    join.entry_no_separator(&format_or_verbatim(&statement));
+                    last_statement = Some(statement);
+                    is_first = false;
+                }
                 _ => {
                     join.entry(statement.syntax(), &format_or_verbatim(&statement));
+                    last_statement = Some(statement);
+                    is_first = false;
+                }
+            }
+        }
+
+        if is_block {
+            if let Some(last_statement) = &last_statement {
+                join.entry_no_separator(&format_trailing_comments_before_delimiter(
+                    last_statement.syntax(),
+                ));
+            }
+        }
+
+        if !trim_block_edges && is_block {
+            if let Some(last_statement) = last_statement {
+                let r_curly_token = node
+                    .syntax()
+                    .last_token()
+                    .and_then(|token| token.next_token());
+
+                let keep_empty_line = r_curly_token.map_or(false, |token| {
+                    token.kind() == T!['}']
+                        && !token.has_leading_comments()
+                        && get_lines_before_token(&token) > 1
+                }) && !comments
+                    .has_trailing_comments(last_statement.syntax());
+
+                if keep_empty_line {
+                    join.entry_no_separator(&empty_line());
                 }
             }
         }
@@ -1,7 +1,8 @@
 use crate::prelude::*;
 use rome_formatter::write;
 
-use rome_js_syntax::{JsSyntaxKind, JsVariableDeclaratorList};
+use crate::js::declarations::variable_declaration::has_line_break_after_kind;
+use rome_js_syntax::{JsSyntaxKind, JsVariableDeclaration, JsVariableDeclaratorList};
 use rome_rowan::AstSeparatedList;
 
 #[derive(Debug, Clone, Default)]
@@ -44,13 +45,27 @@ impl FormatRule<JsVariableDeclaratorList> for FormatJsVariableDeclaratorList {
             None => return Err(FormatError::SyntaxError),
         };
 
-        if length == 1 && !f.comments().has_leading_comments(first_declarator.syntax()) {
+        // `let // comment` places the first declarator on the line after the comment
+        let has_line_break_before_first = node
+            .parent::<JsVariableDeclaration>()
+            .map_or(false, |declaration| {
+                has_line_break_after_kind(declaration.syntax(), f.comments())
+            });
+
+        if length == 1
+            && !has_line_break_before_first
+            && !f.comments().has_leading_comments(first_declarator.syntax())
+        {
             return write!(f, [format_first_declarator]);
         }
 
         write!(
             f,
             [indent(&format_once(|f| {
+                if has_line_break_before_first {
+                    write!(f, [hard_line_break()])?;
+                }
+
                 write!(f, [format_first_declarator])?;
 
                 if length > 1 {
@@ -1,7 +1,7 @@
 use crate::prelude::*;
 use rome_formatter::{format_args, write};
 
-use crate::utils::FormatWithSemicolon;
+use crate::utils::FormatWithStatementSemicolon;
 
 use rome_js_syntax::JsExportFromClause;
 use rome_js_syntax::JsExportFromClauseFields;
@@ -22,7 +22,7 @@ impl FormatNodeRule<JsExportFromClause> for FormatJsExportFromClause {
 
         write!(
             f,
-            [FormatWithSemicolon::new(
+            [FormatWithStatementSemicolon::new(
                 &format_args!(
                     star_token.format(),
                     space(),
@@ -36,7 +36,8 @@ impl FormatNodeRule<JsExportFromClause> for FormatJsExportFromClause {
                         .format()
                         .with_or_empty(|assertion, f| write![f, [space(), assertion]]),
                 ),
-                semicolon_token.as_ref()
+                semicolon_token.as_ref(),
+                node.syntax()
             )]
         )
     }
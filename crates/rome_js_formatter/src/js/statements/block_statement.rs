@@ -36,7 +36,9 @@ impl FormatNodeRule<JsBlockStatement> for FormatJsBlockStatement {
             if has_dangling_comments {
                 write!(
                     f,
-                    [format_dangling_comments(node.syntax()).with_block_indent()]
+                    [format_dangling_comments(node.syntax())
+                        .with_block_indent()
+                        .preserve_gaps()]
                 )?;
             } else if is_non_collapsible(node) {
                 write!(f, [hard_line_break()])?;
@@ -1,7 +1,7 @@
 use crate::prelude::*;
 use rome_formatter::{format_args, write};
 
-use crate::utils::FormatWithSemicolon;
+use crate::utils::FormatWithStatementSemicolon;
 
 use rome_js_syntax::JsDebuggerStatement;
 use rome_js_syntax::JsDebuggerStatementFields;
@@ -18,9 +18,10 @@ impl FormatNodeRule<JsDebuggerStatement> for FormatJsDebuggerStatement {
 
         write!(
             f,
-            [FormatWithSemicolon::new(
+            [FormatWithStatementSemicolon::new(
                 &format_args!(debugger_token.format()),
-                semicolon_token.as_ref()
+                semicolon_token.as_ref(),
+                node.syntax()
             ),]
         )
     }
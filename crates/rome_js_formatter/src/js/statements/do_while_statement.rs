@@ -1,6 +1,6 @@
 use crate::prelude::*;
 
-use crate::utils::{FormatStatementBody, FormatWithSemicolon};
+use crate::utils::{FormatStatementBody, FormatWithStatementSemicolon};
 use rome_formatter::{format_args, write};
 use rome_js_syntax::JsDoWhileStatementFields;
 use rome_js_syntax::{JsAnyStatement, JsDoWhileStatement};
@@ -33,7 +33,20 @@ impl FormatNodeRule<JsDoWhileStatement> for FormatJsDoWhileStatement {
                 ])]
             )?;
 
-            if matches!(body, JsAnyStatement::JsBlockStatement(_)) {
+            // Keep a line comment trailing the `}` of the body on the line of the `}`:
+            //
+            // ```javascript
+            // do {
+            // } // end
+            // while (test);
+            // ```
+            let has_trailing_line_comment = f
+                .comments()
+                .trailing_comments(body.syntax())
+                .iter()
+                .any(|comment| comment.kind().is_line());
+
+            if matches!(body, JsAnyStatement::JsBlockStatement(_)) && !has_trailing_line_comment {
                 write!(f, [space()])?;
             } else {
                 write!(f, [hard_line_break()])?;
@@ -53,9 +66,10 @@ impl FormatNodeRule<JsDoWhileStatement> for FormatJsDoWhileStatement {
 
         write!(
             f,
-            [FormatWithSemicolon::new(
+            [FormatWithStatementSemicolon::new(
                 &format_statement,
-                semicolon_token.as_ref()
+                semicolon_token.as_ref(),
+                node.syntax()
             )]
         )
     }
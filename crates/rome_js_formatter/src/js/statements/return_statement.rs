@@ -1,5 +1,8 @@
 use crate::prelude::*;
-use crate::utils::{FormatWithSemicolon, JsAnyBinaryLikeExpression, JsAnyBinaryLikeLeftExpression};
+use crate::utils::{
+    can_omit_semicolon, FormatWithStatementSemicolon, JsAnyBinaryLikeExpression,
+    JsAnyBinaryLikeLeftExpression,
+};
 
 use rome_formatter::{format_args, write, CstFormatContext};
 
@@ -51,8 +54,16 @@ impl Format<JsFormatContext> for JsAnyStatementWithArgument {
                 .or_else(|| comments.dangling_comments(self.syntax()).last())
                 .map_or(false, |comment| comment.kind().is_line());
 
+            let format_semicolon = format_with(|f| {
+                if f.options().semicolons().is_as_needed() && can_omit_semicolon(self.syntax()) {
+                    write!(f, [format_removed(&semicolon)])
+                } else {
+                    write!(f, [semicolon.format()])
+                }
+            });
+
             if is_last_comment_line {
-                write!(f, [semicolon.format()])?;
+                write!(f, [format_semicolon])?;
             }
 
             if has_dangling_comments {
@@ -60,14 +71,14 @@ impl Format<JsFormatContext> for JsAnyStatementWithArgument {
             }
 
             if !is_last_comment_line {
-                write!(f, [semicolon.format()])?;
+                write!(f, [format_semicolon])?;
             }
 
             Ok(())
         } else {
             write!(
                 f,
-                [FormatWithSemicolon::new(
+                [FormatWithStatementSemicolon::new(
                     &format_with(|f| {
                         if let Some(argument) = &argument {
                             write!(f, [space(), FormatReturnOrThrowArgument(argument)])?;
@@ -75,7 +86,8 @@ impl Format<JsFormatContext> for JsAnyStatementWithArgument {
 
                         Ok(())
                     }),
-                    None
+                    None,
+                    self.syntax()
                 )]
             )
         }
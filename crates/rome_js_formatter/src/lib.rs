@@ -350,14 +350,21 @@ where
     N: AstNode<Language = JsLanguage>,
 {
     fn fmt(&self, node: &N, f: &mut JsFormatter) -> FormatResult<()> {
-        if self.is_suppressed(node, f) {
-            return write!(f, [format_suppressed_node(node.syntax())]);
-        }
+        // Fail gracefully instead of overflowing the stack when formatting deeply nested trees
+        let max_depth = f.options().max_depth();
+        f.state_mut().enter_node(max_depth)?;
 
-        self.fmt_leading_comments(node, f)?;
-        self.fmt_node(node, f)?;
-        self.fmt_dangling_comments(node, f)?;
-        self.fmt_trailing_comments(node, f)
+        let result = if self.is_suppressed(node, f) {
+            write!(f, [format_suppressed_node(node.syntax())])
+        } else {
+            self.fmt_leading_comments(node, f)
+                .and_then(|_| self.fmt_node(node, f))
+                .and_then(|_| self.fmt_dangling_comments(node, f))
+                .and_then(|_| self.fmt_trailing_comments(node, f))
+        };
+
+        f.state_mut().exit_node();
+        result
     }
 
     /// Formats the node without comments. Ignores any suppression comments.
@@ -545,6 +552,20 @@ pub fn format_node(
     rome_formatter::format_node(root, JsFormatLanguage::new(options))
 }
 
+/// Formats a JavaScript (and its super languages) file and writes the result to `output`,
+/// printing the top-level statements as soon as they're formatted.
+///
+/// Prefer this over [format_node] for very large files where holding the formatted document
+/// of the whole file in memory is too expensive. The returned [Printed] holds the source markers
+/// and verbatim ranges of the printed code, its code is empty.
+pub fn format_node_to_writer(
+    options: JsFormatOptions,
+    root: &JsSyntaxNode,
+    output: &mut dyn std::fmt::Write,
+) -> FormatResult<Printed> {
+    rome_formatter::format_node_to_writer(root, JsFormatLanguage::new(options), output)
+}
+
 /// Formats a single node within a file, supported by Rome.
 ///
 /// This runs a simple heuristic to determine the initial indentation
@@ -562,16 +583,25 @@ pub fn format_sub_tree(options: JsFormatOptions, root: &JsSyntaxNode) -> FormatR
 #[cfg(test)]
 mod tests {
 
-    use super::{format_node, format_range};
+    use super::{format_node, format_range, JsFormatLanguage};
 
-    use crate::context::JsFormatOptions;
+    use crate::comments::{JsCommentStyle, JsComments};
+    use crate::context::{JsFormatContext, JsFormatOptions};
+    use crate::prelude::*;
     use rome_diagnostics::file::FileId;
-    use rome_formatter::IndentStyle;
+    use rome_formatter::comments::Comments;
+    use rome_formatter::{
+        format_args, write, FormatLanguage, Formatted, IndentStyle, LineWidth, TransformSourceMap,
+    };
     use rome_js_parser::{parse, parse_script};
-    use rome_js_syntax::SourceType;
-    use rome_rowan::{TextRange, TextSize};
-
-    use crate::check_reformat::{check_reformat, CheckReformatParams};
+    use rome_js_syntax::{
+        JsLanguage, JsSyntaxKind, JsSyntaxNode, JsVariableDeclarationClause, SourceType,
+    };
+    use rome_rowan::{AstNode, Direction, TextRange, TextSize, TriviaPieceKind};
+
+    use crate::check_reformat::{
+        assert_comment_reflow_stable, check_reformat, CheckReformatParams,
+    };
     use crate::context::trailing_comma::TrailingComma;
 
     #[test]
@@ -835,4 +865,607 @@ const a = [
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn format_depth_exceeded() {
+        let src = format!("{}a{};", "[".repeat(20), "]".repeat(20));
+
+        let syntax = SourceType::js_module();
+        let tree = parse(&src, FileId::zero(), syntax);
+
+        let result = format_node(
+            JsFormatOptions::new(syntax).with_max_depth(20),
+            &tree.syntax(),
+        );
+        assert_eq!(result.err(), Some(FormatError::DepthExceeded));
+
+        let result = format_node(JsFormatOptions::new(syntax), &tree.syntax());
+        assert!(result.is_ok());
+    }
+
+    /// Formats `content` in a context holding the comments of `root`, for the tests of the
+    /// format builders and context APIs that aren't observable in the formatted output of a file
+    fn format_with_comments(
+        root: &JsSyntaxNode,
+        options: JsFormatOptions,
+        content: &dyn Format<JsFormatContext>,
+    ) -> Formatted<JsFormatContext> {
+        let comments = JsComments::from_node(root, &JsCommentStyle, None);
+        let context = JsFormatContext::new(options, comments);
+
+        rome_formatter::format!(context, [content]).unwrap()
+    }
+
+    #[test]
+    fn declaration_clause_layout() {
+        let cases = [
+            ("const a = 1;", false),
+            ("const a = function () {\n\treturn 1;\n};", true),
+        ];
+
+        let syntax = SourceType::js_module();
+
+        for (src, has_hard_break) in cases {
+            let tree = parse(src, FileId::zero(), syntax);
+            let clause = tree
+                .syntax()
+                .descendants()
+                .find_map(JsVariableDeclarationClause::cast)
+                .unwrap();
+
+            let formatted = format_with_comments(
+                &tree.syntax(),
+                JsFormatOptions::new(syntax),
+                &clause.format(),
+            );
+            let layout = formatted
+                .context()
+                .declaration_clause_layout(&clause)
+                .unwrap();
+
+            assert_eq!(layout.has_hard_break, has_hard_break, "source: {src:?}");
+        }
+    }
+
+    #[test]
+    fn declaration_clause_layout_exceeding_line_width() {
+        let long_string = "a".repeat(80);
+        let cases = [
+            (String::from("const a = 1;"), "const a = 1;"),
+            (format!("const a = \"{long_string}\";"), "// broken"),
+        ];
+
+        let syntax = SourceType::js_module();
+
+        for (src, expected) in cases {
+            let tree = parse(&src, FileId::zero(), syntax);
+            let clause = tree
+                .syntax()
+                .descendants()
+                .find_map(JsVariableDeclarationClause::cast)
+                .unwrap();
+
+            let content = format_with(|f| {
+                write!(f, [clause.format()])?;
+
+                let layout = f.context().declaration_clause_layout(&clause).unwrap();
+                assert!(!layout.has_hard_break, "source: {src:?}");
+
+                write!(
+                    f,
+                    [if_group_breaks(&text("// broken")).with_group_id(Some(layout.group_id))]
+                )
+            });
+
+            let printed =
+                format_with_comments(&tree.syntax(), JsFormatOptions::new(syntax), &content)
+                    .print()
+                    .unwrap();
+            assert!(
+                printed.as_code().ends_with(expected),
+                "source: {src:?}, printed: {:?}",
+                printed.as_code()
+            );
+        }
+    }
+
+    #[test]
+    fn format_comment_at_end_of_file() {
+        let cases = [
+            (
+                "statement();\n// last comment",
+                "statement();\n// last comment\n",
+            ),
+            (
+                "statement(); // last comment",
+                "statement(); // last comment\n",
+            ),
+            (
+                "statement(); // last comment\n\n",
+                "statement(); // last comment\n",
+            ),
+            ("// last comment", "// last comment\n"),
+            (
+                "statement();\n/* last comment */",
+                "statement();\n/* last comment */\n",
+            ),
+            (
+                "statement(); /* last comment */",
+                "statement(); /* last comment */\n",
+            ),
+        ];
+
+        let syntax = SourceType::js_module();
+        for (src, expected) in cases {
+            let tree = parse(src, FileId::zero(), syntax);
+            let result = format_node(JsFormatOptions::new(syntax), &tree.syntax())
+                .unwrap()
+                .print()
+                .unwrap();
+
+            assert_eq!(result.as_code(), expected, "input: {src:?}");
+        }
+    }
+
+    #[test]
+    fn format_comments_inside_verbatim_once() {
+        let cases = [
+            "// rome-ignore format: test\nconst  a = [1, // one\n  2 /* two */];\n",
+            "foo(/* rome-ignore format: test */ (a  /* inner */), b);\n",
+            "/* rome-ignore format: test */ a  /* c */ .b;\n",
+        ];
+
+        let syntax = SourceType::js_module();
+        for src in cases {
+            let tree = parse(src, FileId::zero(), syntax);
+            let result = format_node(JsFormatOptions::new(syntax), &tree.syntax())
+                .unwrap()
+                .print()
+                .unwrap();
+
+            assert_eq!(result.as_code(), src);
+        }
+    }
+
+    #[test]
+    fn format_aligned_block_comments() {
+        let src = r#"function f() {
+        /**
+    * Doc
+            */
+    a; /* x */
+        /*
+   ragged
+      comment */
+    b;
+}
+class A {
+        /*
+           * dangling
+    */
+}
+"#;
+
+        let expected = r#"function f() {
+	/**
+	 * Doc
+	 */
+	a; /* x */
+	/*
+   ragged
+      comment */
+	b;
+}
+class A {
+	/*
+	 * dangling
+	 */
+}
+"#;
+
+        let syntax = SourceType::js_module();
+        let tree = parse(src, FileId::zero(), syntax);
+        let result = format_node(JsFormatOptions::new(syntax), &tree.syntax())
+            .unwrap()
+            .print()
+            .unwrap();
+
+        assert_eq!(result.as_code(), expected);
+        assert_comment_reflow_stable(src, JsFormatOptions::new(syntax));
+    }
+
+    #[test]
+    fn format_soft_dangling_comments() {
+        let cases = [
+            ("{ /* todo */ }", "{ /* todo */ }"),
+            ("{ /* a */\n/* b */ }", "{ /* a */ /* b */ }"),
+            ("{ // todo\n}", "{\n\t// todo\n}"),
+            ("{ /* a */ // b\n}", "{\n\t/* a */\n\t// b\n}"),
+        ];
+
+        let syntax = SourceType::js_module();
+
+        for (src, expected) in cases {
+            let tree = parse(src, FileId::zero(), syntax);
+            let block = tree
+                .syntax()
+                .descendants()
+                .find(|node| node.kind() == JsSyntaxKind::JS_BLOCK_STATEMENT)
+                .unwrap();
+
+            let content = format_with(|f| {
+                write!(
+                    f,
+                    [
+                        text("{"),
+                        format_dangling_comments(&block).with_block_indent().soft(),
+                        text("}")
+                    ]
+                )
+            });
+            let result =
+                format_with_comments(&tree.syntax(), JsFormatOptions::new(syntax), &content)
+                    .print()
+                    .unwrap();
+
+            assert_eq!(result.as_code(), expected, "source: {src:?}");
+        }
+    }
+
+    #[test]
+    fn format_relocated_comments_with_lines() {
+        let syntax = SourceType::js_module();
+        let tree = parse(
+            "a;\n// line\nb;\n\n\n/* block */ c;",
+            FileId::zero(),
+            syntax,
+        );
+
+        let statements: Vec<_> = tree
+            .syntax()
+            .descendants()
+            .filter(|node| node.kind() == JsSyntaxKind::JS_EXPRESSION_STATEMENT)
+            .collect();
+        let (line, block) = (&statements[1], &statements[2]);
+
+        let cases = [
+            (line, None, "x\n// line\ny"),
+            (line, Some(2), "x\n\n// line\ny"),
+            (line, Some(0), "x // line\ny"),
+            (block, None, "x\n\n/* block */ y"),
+            (block, Some(0), "x /* block */ y"),
+        ];
+
+        for (statement, leading_lines, expected) in cases {
+            let content = format_with(|f| {
+                let comments = f.comments().clone();
+                let relocated = format_relocated_comments(comments.leading_comments(statement));
+
+                match leading_lines {
+                    Some(lines) => write!(f, [text("x"), relocated.with_leading_lines(lines)])?,
+                    None => write!(f, [text("x"), relocated])?,
+                }
+
+                write!(f, [text("y")])
+            });
+
+            let result =
+                format_with_comments(&tree.syntax(), JsFormatOptions::new(syntax), &content)
+                    .print()
+                    .unwrap();
+
+            assert_eq!(
+                result.as_code(),
+                expected,
+                "leading_lines: {leading_lines:?}"
+            );
+        }
+
+        // The trailing line break of a line comment can't be removed
+        let content = format_with(|f| {
+            let comments = f.comments().clone();
+
+            write!(
+                f,
+                [
+                    text("x"),
+                    format_relocated_comments(comments.leading_comments(block))
+                        .with_leading_lines(1)
+                        .with_trailing_lines(1),
+                    format_relocated_comments(comments.leading_comments(line))
+                        .with_trailing_lines(0),
+                    text("y")
+                ]
+            )
+        });
+
+        let result = format_with_comments(&tree.syntax(), JsFormatOptions::new(syntax), &content)
+            .print()
+            .unwrap();
+
+        assert_eq!(result.as_code(), "x\n/* block */\n// line\ny");
+    }
+
+    #[test]
+    fn format_trailing_comments_footnotes() {
+        let syntax = SourceType::js_module();
+        let tree = parse(
+            "a; // short\nb; // a comment exceeding the line width\n",
+            FileId::zero(),
+            syntax,
+        );
+
+        let statements: Vec<_> = tree
+            .syntax()
+            .descendants()
+            .filter(|node| node.kind() == JsSyntaxKind::JS_EXPRESSION_STATEMENT)
+            .collect();
+
+        let content = format_with(|f| {
+            write!(
+                f,
+                [
+                    text("x;"),
+                    format_trailing_comments(&statements[0]).with_footnotes(),
+                    hard_line_break(),
+                    text("y;"),
+                    format_trailing_comments(&statements[1]).with_footnotes()
+                ]
+            )
+        });
+        let options =
+            JsFormatOptions::new(syntax).with_line_width(LineWidth::try_from(20).unwrap());
+        let result = format_with_comments(&tree.syntax(), options, &content)
+            .print()
+            .unwrap();
+
+        // The comment that doesn't fit moves to its own line, indented under the statement
+        assert_eq!(
+            result.as_code(),
+            "x; // short\ny;\n\t// a comment exceeding the line width"
+        );
+    }
+
+    #[test]
+    fn comment_reflow_stable() {
+        let cases = [
+            "function f() {\n        /**\n    * Doc\n            */\n    a; /* x */\n}\n",
+            "/*\n   ragged\n      comment */\nlet a = [\n    1, // one\n    2 /* two */,\n];\n",
+            "/*****\n * A *\n *****/\nclass A {\n        /*\n           * dangling\n    */\n}\n",
+            "call(a, /* inline */ b) // trailing\n// own line\n",
+        ];
+
+        for source in cases {
+            assert_comment_reflow_stable(source, JsFormatOptions::new(SourceType::js_module()));
+        }
+    }
+
+    /// The content written by `format_only_if_breaks` is covered by the trailing commas of
+    /// the array fixtures, `format_only_if_fits` isn't used by any node
+    #[test]
+    fn format_only_if_fits() {
+        let syntax = SourceType::js_module();
+        let tree = parse("a", FileId::zero(), syntax);
+        let token = tree.syntax().first_token().unwrap();
+
+        let cases = [(false, "[a,]"), (true, "[\na\n]")];
+
+        for (expand, expected) in cases {
+            let line = format_with(|f| {
+                if expand {
+                    write!(f, [hard_line_break()])
+                } else {
+                    Ok(())
+                }
+            });
+
+            let content = format_with(|f| {
+                write!(
+                    f,
+                    [group(&format_args![
+                        text("["),
+                        line,
+                        text("a"),
+                        format_only_if_fits(&token, &text(",")),
+                        line,
+                        text("]")
+                    ])]
+                )
+            });
+            let result =
+                format_with_comments(&tree.syntax(), JsFormatOptions::new(syntax), &content)
+                    .print()
+                    .unwrap();
+
+            assert_eq!(result.as_code(), expected, "expand: {expand:?}");
+        }
+    }
+
+    #[test]
+    fn format_replaced_without_trivia() {
+        let syntax = SourceType::js_module();
+        let tree = parse("a", FileId::zero(), syntax);
+        let token = tree.syntax().first_token().unwrap();
+        let with_skipped = token.with_leading_trivia([
+            (TriviaPieceKind::Skipped, "@"),
+            (TriviaPieceKind::Whitespace, " "),
+        ]);
+        let root = tree
+            .syntax()
+            .replace_child(token.into(), with_skipped.into())
+            .unwrap();
+
+        let token = root.first_token().unwrap();
+        let eof = root.last_token().unwrap();
+
+        for (without_trivia, expected) in [(false, "@ b"), (true, "b")] {
+            let content = format_with(|f| {
+                assert!(f.comments().has_skipped(&token));
+
+                let content = text("b");
+                let replaced = format_replaced(&token, &content);
+                if without_trivia {
+                    write!(f, [replaced.without_trivia(), format_removed(&eof)])?;
+                } else {
+                    write!(f, [replaced, format_removed(&eof)])?;
+                }
+
+                // The replaced token is consumed, even if its trivia isn't printed
+                f.state().assert_formatted_all_tokens(&root);
+                Ok(())
+            });
+
+            let result = format_with_comments(&root, JsFormatOptions::new(syntax), &content)
+                .print()
+                .unwrap();
+            assert_eq!(
+                result.as_code(),
+                expected,
+                "without_trivia: {without_trivia}"
+            );
+        }
+    }
+
+    #[test]
+    fn detect_indent_style() {
+        let cases = [
+            (
+                "function f() {\n\tif (a) {\n\t\tb();\n\t}\n}",
+                IndentStyle::Tab,
+            ),
+            (
+                "function f() {\n  if (a) {\n    b();\n  }\n}",
+                IndentStyle::Space(2),
+            ),
+            (
+                "function f() {\n    if (a) {\n\n        b();\n    }\n}",
+                IndentStyle::Space(4),
+            ),
+            // No indentation
+            ("a();\nb();", IndentStyle::Space(3)),
+            // As many lines indented with tabs as with spaces
+            ("{\n\ta();\n}\n{\n  b();\n}", IndentStyle::Space(3)),
+        ];
+
+        let syntax = SourceType::js_module();
+        for (src, expected) in cases {
+            let tree = parse(src, FileId::zero(), syntax);
+
+            assert_eq!(
+                rome_formatter::detect_indent_style(&tree.syntax(), IndentStyle::Space(3)),
+                expected,
+                "input: {src:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn format_node_to_writer_streams_statements() {
+        /// Records every chunk written to the output
+        #[derive(Default)]
+        struct Chunks(Vec<String>);
+
+        impl std::fmt::Write for Chunks {
+            fn write_str(&mut self, s: &str) -> std::fmt::Result {
+                self.0.push(s.to_string());
+                Ok(())
+            }
+        }
+
+        let src = r#"a(   ); // trailing
+/** leading */
+function f(  ) {
+    return    1
+
+
+}
+
+
+class A { m() {} }
+const  b = [1,2,3]; let c
+"#;
+
+        for source_type in [SourceType::js_module(), SourceType::js_script()] {
+            let tree = parse(src, FileId::zero(), source_type.clone());
+            let options = JsFormatOptions::new(source_type);
+
+            let expected = format_node(options.clone(), &tree.syntax()).unwrap();
+            let expected = expected.print().unwrap();
+
+            let mut chunks = Chunks::default();
+            let printed =
+                super::format_node_to_writer(options, &tree.syntax(), &mut chunks).unwrap();
+
+            assert_eq!(chunks.0.concat(), expected.as_code());
+            assert_eq!(printed.sourcemap(), expected.sourcemap());
+            assert_eq!(printed.verbatim_ranges(), expected.verbatim_ranges());
+            assert_eq!(chunks.0.len(), 5, "chunks: {:#?}", chunks.0);
+        }
+    }
+
+    #[test]
+    fn dropped_comments_without_strict_comments() {
+        /// Formats the tokens of the tree without their comments
+        #[derive(Default)]
+        struct FormatTokensOnly;
+
+        impl FormatRule<JsSyntaxNode> for FormatTokensOnly {
+            type Context = JsFormatContext;
+
+            fn fmt(&self, node: &JsSyntaxNode, f: &mut JsFormatter) -> FormatResult<()> {
+                for token in node.descendants_tokens(Direction::Next) {
+                    f.state_mut().track_token(&token);
+                    write!(
+                        f,
+                        [dynamic_text(
+                            token.text_trimmed(),
+                            token.text_trimmed_range().start()
+                        )]
+                    )?;
+                }
+
+                Ok(())
+            }
+        }
+
+        struct DroppingCommentsLanguage(JsFormatLanguage);
+
+        impl FormatLanguage for DroppingCommentsLanguage {
+            type SyntaxLanguage = JsLanguage;
+            type Context = JsFormatContext;
+            type CommentStyle = JsCommentStyle;
+            type FormatRule = FormatTokensOnly;
+
+            fn options(&self) -> &JsFormatOptions {
+                self.0.options()
+            }
+
+            fn create_context(
+                self,
+                comments: Comments<JsLanguage>,
+                source_map: Option<TransformSourceMap>,
+            ) -> JsFormatContext {
+                self.0.create_context(comments, source_map)
+            }
+        }
+
+        let src = "a; // dropped\nb;\n";
+        let tree = parse_script(src, FileId::zero());
+        let options = JsFormatOptions::new(SourceType::js_script()).with_strict_comments(false);
+
+        let formatted = rome_formatter::format_node(
+            &tree.syntax(),
+            DroppingCommentsLanguage(JsFormatLanguage::new(options)),
+        )
+        .unwrap();
+
+        let dropped: Vec<_> = formatted
+            .dropped_comments()
+            .iter()
+            .map(|comment| comment.piece().text())
+            .collect();
+
+        assert_eq!(dropped, ["// dropped"]);
+        assert_eq!(formatted.print().unwrap().as_code(), "a;b;");
+    }
 }
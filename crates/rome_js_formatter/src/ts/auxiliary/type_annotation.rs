@@ -1,6 +1,6 @@
 use crate::prelude::*;
 
-use rome_formatter::write;
+use rome_formatter::{format_args, write};
 use rome_js_syntax::{TsTypeAnnotation, TsTypeAnnotationFields};
 
 #[derive(Debug, Clone, Default)]
@@ -9,9 +9,52 @@ pub struct FormatTsTypeAnnotation;
 impl FormatNodeRule<TsTypeAnnotation> for FormatTsTypeAnnotation {
     fn fmt_fields(&self, node: &TsTypeAnnotation, f: &mut JsFormatter) -> FormatResult<()> {
         let TsTypeAnnotationFields { colon_token, ty } = node.as_fields();
-        let colon = colon_token.format();
-        let ty = ty.format();
+        let ty = ty?;
+        let comments = f.comments().clone();
 
-        write![f, [colon, space(), ty]]
+        let format_annotation = format_with(|f| {
+            // Indents the type if it starts on the line following a comment after the colon
+            // ```javascript
+            // const a: // comment
+            //     string = "test";
+            // ```
+            if comments.has_leading_own_line_comment(ty.syntax()) {
+                write!(f, [colon_token.format(), space(), indent(&ty.format())])
+            } else {
+                write!(f, [colon_token.format(), space(), ty.format()])
+            }
+        });
+
+        let has_own_line_leading_comment = comments
+            .leading_comments(node.syntax())
+            .iter()
+            .any(|comment| comment.lines_before() > 0);
+
+        // Keeps the comments on their own line before the colon
+        // ```javascript
+        // let a
+        //     // comment
+        //     : string;
+        // ```
+        if has_own_line_leading_comment {
+            write!(
+                f,
+                [indent(&format_args![
+                    hard_line_break(),
+                    format_leading_comments(node.syntax()),
+                    format_annotation
+                ])]
+            )
+        } else {
+            write!(
+                f,
+                [format_leading_comments(node.syntax()), format_annotation]
+            )
+        }
+    }
+
+    fn fmt_leading_comments(&self, _: &TsTypeAnnotation, _: &mut JsFormatter) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
     }
 }
@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use crate::utils::FormatWithSemicolon;
+use crate::utils::FormatWithStatementSemicolon;
 
 use rome_formatter::{format_args, write};
 use rome_js_syntax::TsExportAsNamespaceClause;
@@ -23,7 +23,7 @@ impl FormatNodeRule<TsExportAsNamespaceClause> for FormatTsExportAsNamespaceClau
 
         write!(
             f,
-            [FormatWithSemicolon::new(
+            [FormatWithStatementSemicolon::new(
                 &format_args!(
                     as_token.format(),
                     space(),
@@ -31,7 +31,8 @@ impl FormatNodeRule<TsExportAsNamespaceClause> for FormatTsExportAsNamespaceClau
                     space(),
                     name.format()
                 ),
-                semicolon_token.as_ref()
+                semicolon_token.as_ref(),
+                node.syntax()
             )]
         )
     }
@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use crate::utils::FormatWithSemicolon;
+use crate::utils::FormatWithStatementSemicolon;
 
 use rome_formatter::{format_args, write};
 use rome_js_syntax::TsExportAssignmentClause;
@@ -18,9 +18,10 @@ impl FormatNodeRule<TsExportAssignmentClause> for FormatTsExportAssignmentClause
 
         write!(
             f,
-            [FormatWithSemicolon::new(
+            [FormatWithStatementSemicolon::new(
                 &format_args!(eq_token.format(), space(), expression.format()),
-                semicolon_token.as_ref()
+                semicolon_token.as_ref(),
+                node.syntax()
             )]
         )
     }
@@ -60,6 +60,13 @@ where
                     write!(f, [FormatTrailingComma::ES5])?;
                 };
 
+                if index == last_index {
+                    write!(
+                        f,
+                        [format_trailing_comments_before_delimiter(node.syntax())]
+                    )?;
+                }
+
                 Ok(())
             }),
         );
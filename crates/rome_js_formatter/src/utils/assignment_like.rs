@@ -490,7 +490,12 @@ impl JsAnyAssignmentLike {
             JsAnyAssignmentLike::JsVariableDeclarator(variable_declarator) => {
                 if let Some(initializer) = variable_declarator.initializer() {
                     let eq_token = initializer.eq_token()?;
-                    write!(f, [space(), eq_token.format()])?
+                    let spacing = f.options().assignment_spacing();
+
+                    write!(
+                        f,
+                        [spaces(usize::from(spacing.before())), eq_token.format()]
+                    )?
                 }
                 Ok(())
             }
@@ -604,6 +609,7 @@ impl JsAnyAssignmentLike {
     fn layout(
         &self,
         is_left_short: bool,
+        has_inline_initializer_comments: bool,
         f: &mut Formatter<JsFormatContext>,
     ) -> FormatResult<AssignmentLikeLayout> {
         if self.has_only_left_hand_side() {
@@ -637,6 +643,10 @@ impl JsAnyAssignmentLike {
             return Ok(AssignmentLikeLayout::BreakAfterOperator);
         }
 
+        if has_inline_initializer_comments {
+            return Ok(AssignmentLikeLayout::NeverBreakAfterOperator);
+        }
+
         if is_left_short {
             return Ok(AssignmentLikeLayout::NeverBreakAfterOperator);
         }
@@ -826,6 +836,51 @@ impl JsAnyAssignmentLike {
         Ok(is_complex_destructuring || has_complex_type_annotation || is_complex_type_alias)
     }
 
+    /// Checks if the initializer of a variable declarator starts with inline block comments that are on the
+    /// same line as the `=` token and the initializer's value:
+    ///
+    /// ```javascript
+    /// const x = /* type hint */ value;
+    /// ```
+    ///
+    /// These comments stay inline, right after the `=`: the assignment doesn't break after the operator.
+    /// Initializers that require to break after the operator, like binary expressions, get indented instead.
+    ///
+    /// Comments followed by a parenthesized expression are excluded because they are type casts,
+    /// like `/** @type {string} */ (value)`, that may move to the next line with their expression.
+    fn has_inline_initializer_comments(&self, comments: &JsComments) -> SyntaxResult<bool> {
+        let initializer = match self {
+            JsAnyAssignmentLike::JsVariableDeclarator(declarator) => declarator.initializer(),
+            _ => None,
+        };
+
+        let initializer = match initializer {
+            Some(initializer) => initializer,
+            None => return Ok(false),
+        };
+
+        let expression = initializer.expression()?;
+
+        if matches!(expression, JsAnyExpression::JsParenthesizedExpression(_)) {
+            return Ok(false);
+        }
+
+        // The comments are leading comments of the initializer clause, or of its value
+        // for the comments that have been placed by a comment handler
+        let mut leading_comments = comments
+            .leading_comments(initializer.syntax())
+            .iter()
+            .chain(comments.leading_comments(expression.syntax()))
+            .peekable();
+
+        Ok(leading_comments.peek().is_some()
+            && leading_comments.all(|comment| {
+                comment.kind().is_inline_block()
+                    && comment.lines_before() == 0
+                    && comment.lines_after() == 0
+            }))
+    }
+
     /// Checks if the the current assignment is eligible for [AssignmentLikeLayout::BreakAfterOperator]
     ///
     /// This function is small wrapper around [should_break_after_operator] because it has to work
@@ -914,7 +969,9 @@ impl Format<JsFormatContext> for JsAnyAssignmentLike {
             // Compare name only if we are in a position of computing it.
             // If not (for example, left is not an identifier), then let's fallback to false,
             // so we can continue the chain of checks
-            let layout = self.layout(is_left_short, f)?;
+            let has_inline_initializer_comments =
+                self.has_inline_initializer_comments(f.context().comments())?;
+            let layout = self.layout(is_left_short, has_inline_initializer_comments, f)?;
 
             let left = format_once(|f| f.write_elements(formatted_left));
             let right = format_with(|f| self.write_right(f, layout));
@@ -935,6 +992,9 @@ impl Format<JsFormatContext> for JsAnyAssignmentLike {
 
                 match layout {
                     AssignmentLikeLayout::OnlyLeft => Ok(()),
+                    AssignmentLikeLayout::BreakAfterOperator if has_inline_initializer_comments => {
+                        write![f, [group(&indent(&format_args![space(), right]))]]
+                    }
                     AssignmentLikeLayout::Fluid => {
                         let group_id = f.group_id("assignment_like");
 
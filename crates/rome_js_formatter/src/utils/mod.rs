@@ -32,10 +32,11 @@ pub(crate) use object_like::JsObjectLike;
 pub(crate) use object_pattern_like::JsObjectPatternLike;
 use rome_formatter::{format_args, write, Buffer};
 use rome_js_syntax::{
-    JsAnyExpression, JsAnyStatement, JsCallExpression, JsInitializerClause, JsLanguage, Modifiers,
+    JsAnyExpression, JsAnyStatement, JsCallExpression, JsInitializerClause, JsLanguage,
+    JsSyntaxKind, Modifiers, T,
 };
 use rome_js_syntax::{JsSyntaxNode, JsSyntaxToken};
-use rome_rowan::{AstNode, AstNodeList};
+use rome_rowan::{AstNode, AstNodeList, Direction};
 pub(crate) use string_utils::*;
 pub(crate) use typescript::{
     is_object_like_type, should_hug_type, union_or_intersection_type_needs_parentheses,
@@ -212,6 +213,10 @@ where
 /// Format a some code followed by an optional semicolon, and performs
 /// semicolon insertion if it was missing in the input source and the
 /// preceding element wasn't an unknown node
+///
+/// The semicolon is written directly after `content`. Trailing line comments of `content` are
+/// deferred with a `line_suffix`, guaranteeing that the semicolon comes before the comment:
+/// `let x = 1 // comment` formats as `let x = 1; // comment`.
 pub struct FormatWithSemicolon<'a> {
     content: &'a dyn Format<JsFormatContext>,
     semicolon: Option<&'a JsSyntaxToken>,
@@ -251,6 +256,105 @@ impl Format<JsFormatContext> for FormatWithSemicolon<'_> {
     }
 }
 
+/// Formats the content of a statement followed by its optional semicolon, honouring the
+/// [Semicolons](crate::context::Semicolons) option.
+///
+/// With `Semicolons::Always`, this is the same as [FormatWithSemicolon]. With `Semicolons::AsNeeded`,
+/// the semicolon is removed unless the statement is followed by a token that would continue it
+/// once the semicolon is gone. For example, the semicolon of `const a = b;` must stay
+/// if the next line starts with `(`, because `const a = b\n(c)()` calls `b`.
+pub struct FormatWithStatementSemicolon<'a> {
+    content: &'a dyn Format<JsFormatContext>,
+    semicolon: Option<&'a JsSyntaxToken>,
+    statement: &'a JsSyntaxNode,
+}
+
+impl<'a> FormatWithStatementSemicolon<'a> {
+    pub fn new(
+        content: &'a dyn Format<JsFormatContext>,
+        semicolon: Option<&'a JsSyntaxToken>,
+        statement: &'a JsSyntaxNode,
+    ) -> Self {
+        Self {
+            content,
+            semicolon,
+            statement,
+        }
+    }
+}
+
+impl Format<JsFormatContext> for FormatWithStatementSemicolon<'_> {
+    fn fmt(&self, f: &mut JsFormatter) -> FormatResult<()> {
+        if f.options().semicolons().is_always() || !can_omit_semicolon(self.statement) {
+            return FormatWithSemicolon::new(self.content, self.semicolon).fmt(f);
+        }
+
+        write!(f, [self.content])?;
+
+        if let Some(semicolon) = self.semicolon {
+            write!(f, [format_removed(semicolon)])?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns `true` if the semicolon ending `statement` can be removed without changing how
+/// the program parses.
+///
+/// Only statements that are the last part of an item of a statement, module item, or directive list
+/// qualify: the semicolons of `if (a) b = 1; else c()` or of a `for` loop body are kept. The
+/// semicolon is kept as well if the next item starts with a token that continues an expression:
+/// `(`, `[`, `` ` ``, `+`, `-`, `/`, a regular expression, or a `<` type assertion, ignoring
+/// the empty statements in between: `a = b;\n;[1].forEach(f)` keeps the semicolon after `b`.
+pub(crate) fn can_omit_semicolon(statement: &JsSyntaxNode) -> bool {
+    let item = statement.ancestors().find(|node| {
+        node.parent().map_or(false, |parent| {
+            matches!(
+                parent.kind(),
+                JsSyntaxKind::JS_STATEMENT_LIST
+                    | JsSyntaxKind::JS_MODULE_ITEM_LIST
+                    | JsSyntaxKind::JS_DIRECTIVE_LIST
+            )
+        })
+    });
+
+    let item = match item {
+        Some(item) if item.text_range().end() == statement.text_range().end() => item,
+        _ => return false,
+    };
+
+    // Skip the empty statements following the item: the statement lists remove them, the token
+    // following them ends up right after the item once the semicolon is gone.
+    let next_item = item
+        .siblings(Direction::Next)
+        .skip(1)
+        .find(|sibling| sibling.kind() != JsSyntaxKind::JS_EMPTY_STATEMENT);
+
+    let next_token = match next_item {
+        Some(next_item) => next_item.first_token(),
+        None => item
+            .parent()
+            .and_then(|list| list.last_token())
+            .and_then(|token| token.next_token()),
+    };
+
+    !next_token.map_or(false, |token| {
+        matches!(
+            token.kind(),
+            T!['(']
+                | T!['[']
+                | T!['`']
+                | T![+]
+                | T![-]
+                | T![/]
+                | T![/=]
+                | T![<]
+                | JsSyntaxKind::JS_REGEX_LITERAL
+        )
+    })
+}
+
 /// A call like expression is one of:
 ///
 /// - [JsNewExpression]
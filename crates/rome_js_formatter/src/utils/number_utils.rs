@@ -60,7 +60,7 @@ struct FormatNumberLiteralExponent {
     first_non_zero_index: Option<NonZeroUsize>,
 }
 // Regex-free version of https://github.com/prettier/prettier/blob/ca246afacee8e6d5db508dae01730c9523bbff1d/src/common/util.js#L341-L356
-fn format_trimmed_number(text: &str) -> Cow<str> {
+pub(crate) fn format_trimmed_number(text: &str) -> Cow<str> {
     let text = text.to_ascii_lowercase_cow();
     let mut copied_or_ignored_chars = 0usize;
     let mut iter = text.chars().enumerate();
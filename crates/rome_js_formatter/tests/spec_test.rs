@@ -3,7 +3,9 @@ use rome_formatter::{FormatOptions, LineWidth};
 use rome_formatter::{IndentStyle, Printed};
 use rome_fs::RomePath;
 use rome_js_formatter::context::trailing_comma::TrailingComma;
-use rome_js_formatter::context::{JsFormatOptions, QuoteProperties, QuoteStyle};
+use rome_js_formatter::context::{
+    AssignmentSpacing, JsFormatOptions, QuoteProperties, QuoteStyle, Semicolons,
+};
 use rome_js_formatter::format_node;
 use rome_js_parser::parse;
 use rome_js_syntax::{ModuleKind, SourceType};
@@ -83,6 +85,21 @@ impl From<SerializableTrailingComma> for TrailingComma {
     }
 }
 
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Deserialize, Serialize)]
+pub enum SerializableSemicolons {
+    Always,
+    AsNeeded,
+}
+
+impl From<SerializableSemicolons> for Semicolons {
+    fn from(test: SerializableSemicolons) -> Self {
+        match test {
+            SerializableSemicolons::Always => Semicolons::Always,
+            SerializableSemicolons::AsNeeded => Semicolons::AsNeeded,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, Copy)]
 pub struct SerializableFormatOptions {
     /// The indent style.
@@ -99,6 +116,24 @@ pub struct SerializableFormatOptions {
 
     /// Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "all".
     pub trailing_comma: Option<SerializableTrailingComma>,
+
+    /// Whether statements end with a semicolon. Defaults to "always".
+    pub semicolons: Option<SerializableSemicolons>,
+
+    /// Whether to remove the empty lines at the edges of blocks. Defaults to `true`.
+    pub trim_block_edges: Option<bool>,
+
+    /// The maximum number of consecutive empty lines preserved around comments. Defaults to 1.
+    pub max_empty_lines: Option<u8>,
+
+    /// Whether the dangling block comments of a node force it to break. Defaults to `false`.
+    pub dangling_comments_force_break: Option<bool>,
+
+    /// The number of spaces between a trailing comment and the code it trails. Defaults to 1.
+    pub trailing_comment_leading_spaces: Option<usize>,
+
+    /// The number of spaces between a declared binding and the `=` of its initializer. Defaults to 1.
+    pub assignment_spacing: Option<u8>,
 }
 
 impl From<SerializableFormatOptions> for JsFormatOptions {
@@ -125,6 +160,15 @@ impl From<SerializableFormatOptions> for JsFormatOptions {
                 test.trailing_comma
                     .map_or_else(|| TrailingComma::All, |value| value.into()),
             )
+            .with_semicolons(
+                test.semicolons
+                    .map_or_else(|| Semicolons::Always, |value| value.into()),
+            )
+            .with_trim_block_edges(test.trim_block_edges.unwrap_or(true))
+            .with_max_empty_lines(test.max_empty_lines.unwrap_or(1))
+            .with_dangling_comments_force_break(test.dangling_comments_force_break == Some(true))
+            .with_trailing_comment_leading_spaces(test.trailing_comment_leading_spaces.unwrap_or(1))
+            .with_assignment_spacing(AssignmentSpacing::new(test.assignment_spacing.unwrap_or(1)))
     }
 }
 
@@ -0,0 +1,21 @@
+a = [1, 2,];
+a = [1, 2, /* c */];
+a = [1, 2 /* a */, /* b */];
+a = [
+  1,
+  2, /* c */
+];
+a = [
+  1,
+  2 /* a */, /* b */
+];
+a = [
+  1,
+  2 // c
+  ,
+];
+f(a, b,);
+f(a, b, /* c */);
+let {a1, b1, /* c */} = x;
+a = {a, b, /* c */};
+function g(a, b, /* c */) {}
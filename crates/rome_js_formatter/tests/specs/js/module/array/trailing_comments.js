@@ -0,0 +1,9 @@
+const a = [1, 2 // two
+];
+
+const b = [
+  1,
+  2, // two
+];
+
+const c = [1, 2 /* two */];
@@ -0,0 +1,23 @@
+/* first
+   second */
+statement();
+
+function f() {
+        /* first line
+second line */
+        statement();
+
+    /** doc comment first line
+  * second line
+       */
+    statement();
+}
+
+if (a) {
+/*    leading spaces are kept
+      after the opening delimiter */
+b();
+}
+
+call(a /* first
+   second */, b);
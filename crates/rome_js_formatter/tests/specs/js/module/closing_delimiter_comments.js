@@ -0,0 +1,27 @@
+function foo() {
+	return 1;
+}  // end of foo
+
+if (a) {
+	b();
+} // end if
+
+do {
+	c();
+}  // end do
+while (d);
+
+call(
+	first,
+	second,
+)  // end call
+
+{
+	e();
+}  // end block
+
+class A {
+	method() {
+		return 2;
+	} // end method
+}
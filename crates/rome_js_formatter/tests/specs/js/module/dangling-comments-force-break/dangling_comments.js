@@ -0,0 +1,5 @@
+f(/* c */);
+import {/* c */} from "a";
+f(
+// c
+);
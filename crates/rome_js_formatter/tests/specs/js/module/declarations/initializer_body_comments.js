@@ -0,0 +1,13 @@
+const handler = () => { /* todo */ };
+
+const lineHandler = () => { // todo
+};
+
+const longParameters = (firstArgument, secondArgument, thirdArgument) => { /* todo */ };
+
+const longComment = () => { /* this comment is long enough to exceed the line width */ };
+
+let first = () => { /* todo */ }, second = () => { // todo
+};
+
+const expression = function () { /* todo */ };
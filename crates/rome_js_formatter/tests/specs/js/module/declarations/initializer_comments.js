@@ -0,0 +1,9 @@
+const x = /* type hint */ value;
+const y = /* type hint */ someVeryLongFunctionName(argumentNumberOne, argumentNumberTwo, three);
+const z = /* type hint */ aVeryLongVariableNameThatIsReallyLong.anotherPropertyAccess.more.stuff;
+const b = /* hint */ "some very long string literal that does not fit on the line at all";
+const c = /* hint */ cond ? someVeryLongFunctionName(argumentNumberOne) : otherValueThatIsLong;
+const d = /* hint */ aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa && bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb && ccccccc;
+const e = /* first */ /* second */ value;
+const f =
+	/* own line */ someVeryLongFunctionName(argumentNumberOne, argumentNumberTwo, three);
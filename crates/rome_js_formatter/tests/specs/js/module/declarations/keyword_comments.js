@@ -0,0 +1,32 @@
+const /* type */ a = 1;
+
+let // trailing line
+	b = 2;
+
+let // trailing line
+	c = 3,
+	d = 4;
+
+const /* first */ /* second */ e = 5;
+
+let /* block */
+	f = 6;
+
+let g = 7,
+	// leading of the declarator
+	h = 8;
+
+export const /* exported */ i = 9;
+
+for (const /* type */ j of list) {
+	use(j);
+}
+
+for (let // trailing line
+	k in object) {
+	use(k);
+}
+
+for (let /* index */ l = 0; l < 1; l++) {
+	use(l);
+}
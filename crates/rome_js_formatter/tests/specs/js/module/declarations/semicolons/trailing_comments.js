@@ -0,0 +1,10 @@
+let a = 1 // no semicolon
+let b = 1; // semicolon
+let c = 1 /* block */
+let d = 1 /* block */;
+let e = 1 // semicolon on next line
+;
+let f = 1, g = 2 // multiple declarators
+let h = veryLongFunctionName(argumentNumberOne, argumentNumberTwo, argumentThree) // breaks
+export let i = 1 // export clause
+export const j = 1; // export clause with semicolon
@@ -0,0 +1,21 @@
+// eslint-disable-next-line no-console
+console.log("a");
+
+const value = /* @__PURE__ */ create();
+
+import path from "path"; // eslint-disable-line no-unused-vars
+
+function f() {
+	// @ts-ignore
+	return missing;
+}
+
+longVariableName = // @ts-ignore
+	first + second;
+
+function g() {
+    /* eslint-disable
+        no-console,
+        no-debugger */
+    console.log(a);
+}
@@ -0,0 +1,21 @@
+/*******************************
+ *  Copyright (c) Rome Tools   *
+ *  License: MIT               *
+ *******************************/
+
+/*=============================
+** Unindented interior lines **
+=============================*/
+let a;
+
+function f() {
+        /*++++++++++++++++++++
+        ++  Over indented   ++
+        ++++++++++++++++++++*/
+    return a;
+}
+
+    /**
+       * Not framed: the stars are re-aligned
+     */
+let b;
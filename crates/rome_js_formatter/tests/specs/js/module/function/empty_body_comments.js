@@ -0,0 +1,18 @@
+function noop() { /* noop */ }
+
+const arrow = () => { /* noop */ };
+
+class A {
+	method() { /* noop */ }
+}
+
+function long() { /* this comment is long enough to exceed the line width of eighty columns */ }
+
+function multiple() { /* a */ /* b */ }
+
+function line() { // noop
+}
+
+function ownLine() {
+	/* noop */
+}
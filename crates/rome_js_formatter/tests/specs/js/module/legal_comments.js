@@ -0,0 +1,10 @@
+/*! lib v1.0.0 | MIT License */ import a from "a";
+
+function f() {
+        /**
+      * @license Apache-2.0
+         */
+  return a;
+}
+
+/* @preserve */ const b = 1;
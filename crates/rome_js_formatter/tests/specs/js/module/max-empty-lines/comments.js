@@ -0,0 +1,14 @@
+a;
+// a
+
+
+
+// b
+
+
+
+b; /* c */
+
+
+
+// d
@@ -0,0 +1,11 @@
+a = {
+	0XFF: 1,
+	1E5: 2,
+	1.50: 3,
+	.5: 4,
+};
+
+class A {
+	0B11 = 1;
+	.5() {}
+}
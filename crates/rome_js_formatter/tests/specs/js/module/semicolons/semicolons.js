@@ -0,0 +1,45 @@
+import a from "a";
+export { a };
+
+const b = 1;
+(function () {})();
+
+let c = 2;
+[1, 2].forEach(log);
+
+let d = 3;
+`template`.length;
+
+let e = 4;
++e;
+
+let f = 5;
+-f;
+
+let g = 6;
+/regex/.test(g);
+
+export const h = 7;
+(() => {})();
+
+let l = 9;
+;[1].forEach(f);
+
+let m = 10;
+;(function () {})();
+
+function i() {
+	const j = 8;
+	return j;
+}
+
+for (const k of list) continue;
+
+if (a) b();
+else c();
+
+do {
+	d();
+} while (e);
+
+debugger;
@@ -7,3 +7,18 @@ if (true) {
 	// rome-ignore format: Tests that ignored empty statements don't get removed
 	;
 }
+
+
+if (true) {
+	;
+
+	a;
+
+}
+
+if (true) {
+	// rome-ignore format: keeps the following statement on its own line
+	;
+
+	a;
+}
@@ -0,0 +1,9 @@
+function f() { a; // a
+}
+
+if (x) {
+  b;
+  // own line
+}
+
+{ c; /* c */ }
@@ -0,0 +1,17 @@
+if (a) {
+	// first
+
+	// second
+	// third
+}
+
+{
+	/* first */
+
+
+	/* second */
+}
+
+while (b) {
+	/* first */ /* second */
+}
@@ -0,0 +1,26 @@
+function f() {
+
+	a;
+
+	b;
+
+}
+
+if (a) {
+
+	// c
+	b; // d
+
+}
+
+switch (a) {
+	case 1:
+
+		b;
+}
+
+function g() {
+	"use strict";
+	a;
+
+}
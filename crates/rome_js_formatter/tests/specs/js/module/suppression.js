@@ -26,4 +26,7 @@ const    expr2    =    {
 
 let a =
     // rome-ignore format: test
-function () {}
\ No newline at end of file
+function () {}
+
+// rome-ignore-all format: file suppressions only apply to the linter
+if(true) statement();
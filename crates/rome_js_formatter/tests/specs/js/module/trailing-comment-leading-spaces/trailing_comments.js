@@ -0,0 +1,9 @@
+a; // line
+b; /* block */
+
+[
+	a,
+	// own line
+];
+
+call(a /* inline */, b);
@@ -0,0 +1,10 @@
+const x /* a */: /* b */ T = v;
+const w /* a */ : /* b */ T /* c */ = /* d */ v;
+
+const y // a
+: T = v;
+const z: // b
+T = v;
+let u
+// own line
+: T;
@@ -0,0 +1,8 @@
+/* license */
+
+
+@sealed
+
+// dangling
+@logged
+class A {}
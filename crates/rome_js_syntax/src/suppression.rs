@@ -2,6 +2,10 @@
 ///
 /// `// rome-ignore { <category> { (<value>) }? }+: <reason>`
 ///
+/// The `rome-ignore-next-line` spelling is equivalent to `rome-ignore`, both
+/// suppress the line following the comment. The `rome-ignore-all` spelling
+/// suppresses the whole file instead.
+///
 /// The category broadly describes what feature is being suppressed (formatting,
 /// linting, ...) with the value being and optional, category-specific name of
 /// a specific element to disable (for instance a specific lint name). A single
@@ -20,6 +24,17 @@ pub struct Suppression<'a> {
     pub categories: Vec<(&'a str, Option<&'a str>)>,
     /// Reason for this suppression comment to exist
     pub reason: &'a str,
+    /// Range of code this suppression applies to
+    pub scope: SuppressionScope,
+}
+
+/// Range of code a [Suppression] applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuppressionScope {
+    /// `rome-ignore` and `rome-ignore-next-line` suppress the line following the comment
+    Line,
+    /// `rome-ignore-all` suppresses the whole file
+    File,
 }
 
 pub fn parse_suppression_comment(comment: &str) -> impl Iterator<Item = Suppression> {
@@ -46,7 +61,18 @@ pub fn parse_suppression_comment(comment: &str) -> impl Iterator<Item = Suppress
         }
 
         // Check for the rome-ignore token or skip the line entirely
-        line = line.strip_prefix("rome-ignore")?.trim_start();
+        line = line.strip_prefix("rome-ignore")?;
+        let scope = match line.strip_prefix("-all") {
+            Some(rest) => {
+                line = rest;
+                SuppressionScope::File
+            }
+            None => {
+                line = line.strip_prefix("-next-line").unwrap_or(line);
+                SuppressionScope::Line
+            }
+        };
+        line = line.trim_start();
 
         let mut categories = Vec::new();
 
@@ -93,7 +119,11 @@ pub fn parse_suppression_comment(comment: &str) -> impl Iterator<Item = Suppress
         }
 
         let reason = line.trim_end();
-        Some(Suppression { categories, reason })
+        Some(Suppression {
+            categories,
+            reason,
+            scope,
+        })
     })
 }
 
@@ -119,7 +149,7 @@ impl PartialEq<SuppressionCategory> for &'_ str {
 
 #[cfg(test)]
 mod tests {
-    use super::{parse_suppression_comment, Suppression};
+    use super::{parse_suppression_comment, Suppression, SuppressionScope};
 
     #[test]
     fn parse_simple_suppression() {
@@ -127,7 +157,8 @@ mod tests {
             parse_suppression_comment("// rome-ignore parse: explanation1").collect::<Vec<_>>(),
             vec![Suppression {
                 categories: vec![("parse", None)],
-                reason: "explanation1"
+                reason: "explanation1",
+                scope: SuppressionScope::Line,
             }],
         );
 
@@ -135,7 +166,8 @@ mod tests {
             parse_suppression_comment("/** rome-ignore parse: explanation2 */").collect::<Vec<_>>(),
             vec![Suppression {
                 categories: vec![("parse", None)],
-                reason: "explanation2"
+                reason: "explanation2",
+                scope: SuppressionScope::Line,
             }],
         );
 
@@ -148,7 +180,8 @@ mod tests {
             .collect::<Vec<_>>(),
             vec![Suppression {
                 categories: vec![("parse", None)],
-                reason: "explanation3"
+                reason: "explanation3",
+                scope: SuppressionScope::Line,
             }],
         );
 
@@ -162,17 +195,46 @@ mod tests {
             .collect::<Vec<_>>(),
             vec![Suppression {
                 categories: vec![("parse", None)],
-                reason: "explanation4"
+                reason: "explanation4",
+                scope: SuppressionScope::Line,
+            }],
+        );
+    }
+
+    #[test]
+    fn parse_next_line_suppression() {
+        assert_eq!(
+            parse_suppression_comment("// rome-ignore-next-line lint(group/rule): explanation")
+                .collect::<Vec<_>>(),
+            vec![Suppression {
+                categories: vec![("lint", Some("group/rule"))],
+                reason: "explanation",
+                scope: SuppressionScope::Line,
             }],
         );
     }
+
+    #[test]
+    fn parse_file_suppression() {
+        assert_eq!(
+            parse_suppression_comment("// rome-ignore-all lint(group/rule): explanation")
+                .collect::<Vec<_>>(),
+            vec![Suppression {
+                categories: vec![("lint", Some("group/rule"))],
+                reason: "explanation",
+                scope: SuppressionScope::File,
+            }],
+        );
+    }
+
     #[test]
     fn parse_unclosed_block_comment_suppressions() {
         assert_eq!(
             parse_suppression_comment("/* rome-ignore format: explanation").collect::<Vec<_>>(),
             vec![Suppression {
                 categories: vec![("format", None)],
-                reason: "explanation"
+                reason: "explanation",
+                scope: SuppressionScope::Line,
             }],
         );
 
@@ -180,7 +242,8 @@ mod tests {
             parse_suppression_comment("/* rome-ignore format: explanation *").collect::<Vec<_>>(),
             vec![Suppression {
                 categories: vec![("format", None)],
-                reason: "explanation"
+                reason: "explanation",
+                scope: SuppressionScope::Line,
             }],
         );
 
@@ -188,7 +251,8 @@ mod tests {
             parse_suppression_comment("/* rome-ignore format: explanation /").collect::<Vec<_>>(),
             vec![Suppression {
                 categories: vec![("format", None)],
-                reason: "explanation"
+                reason: "explanation",
+                scope: SuppressionScope::Line,
             }],
         );
     }
@@ -200,7 +264,8 @@ mod tests {
                 .collect::<Vec<_>>(),
             vec![Suppression {
                 categories: vec![("parse", Some("foo")), ("parse", Some("dog"))],
-                reason: "explanation"
+                reason: "explanation",
+                scope: SuppressionScope::Line,
             }],
         );
 
@@ -209,7 +274,8 @@ mod tests {
                 .collect::<Vec<_>>(),
             vec![Suppression {
                 categories: vec![("parse", Some("bar")), ("parse", Some("cat"))],
-                reason: "explanation"
+                reason: "explanation",
+                scope: SuppressionScope::Line,
             }],
         );
 
@@ -222,7 +288,8 @@ mod tests {
             .collect::<Vec<_>>(),
             vec![Suppression {
                 categories: vec![("parse", Some("yes")), ("parse", Some("frog"))],
-                reason: "explanation"
+                reason: "explanation",
+                scope: SuppressionScope::Line,
             }],
         );
 
@@ -236,7 +303,8 @@ mod tests {
             .collect::<Vec<_>>(),
             vec![Suppression {
                 categories: vec![("parse", Some("wow")), ("parse", Some("fish"))],
-                reason: "explanation"
+                reason: "explanation",
+                scope: SuppressionScope::Line,
             }],
         );
     }
@@ -248,7 +316,8 @@ mod tests {
                 .collect::<Vec<_>>(),
             vec![Suppression {
                 categories: vec![("format", None), ("lint", None)],
-                reason: "explanation"
+                reason: "explanation",
+                scope: SuppressionScope::Line,
             }],
         );
     }
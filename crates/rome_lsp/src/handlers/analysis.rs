@@ -42,7 +42,12 @@ pub(crate) fn code_actions(
     if let Some(filter) = params.context.only {
         for kind in filter {
             if kind == CodeActionKind::QUICKFIX {
-                filters.push(ActionCategory::QuickFix);
+                // The suppression actions are sent to the client as quick fixes
+                filters.extend([
+                    ActionCategory::QuickFix,
+                    ActionCategory::Suppression,
+                    ActionCategory::FileSuppression,
+                ]);
             } else if kind == CodeActionKind::REFACTOR {
                 filters.push(ActionCategory::Refactor);
             } else if kind == FIX_ALL {
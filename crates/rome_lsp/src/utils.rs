@@ -112,7 +112,10 @@ pub(crate) fn code_fix_to_lsp(
     action: CodeAction,
 ) -> lsp::CodeAction {
     // Mark diagnostics emitted by the same rule as resolved by this action
-    let diagnostics: Vec<_> = if matches!(action.category, ActionCategory::QuickFix) {
+    let diagnostics: Vec<_> = if matches!(
+        action.category,
+        ActionCategory::QuickFix | ActionCategory::Suppression | ActionCategory::FileSuppression
+    ) {
         diagnostics
             .iter()
             .filter_map(|d| {
@@ -135,6 +138,8 @@ pub(crate) fn code_fix_to_lsp(
     let kind = match action.category {
         ActionCategory::QuickFix => Some(lsp::CodeActionKind::QUICKFIX),
         ActionCategory::Refactor => Some(lsp::CodeActionKind::REFACTOR),
+        ActionCategory::Suppression => Some(lsp::CodeActionKind::QUICKFIX),
+        ActionCategory::FileSuppression => Some(lsp::CodeActionKind::QUICKFIX),
     };
 
     let suggestion = action.suggestion;
@@ -653,7 +653,10 @@ async fn pull_quick_fixes() -> Result<()> {
         })
         .collect();
 
-    assert_eq!(code_actions.as_slice(), &["Use ==="]);
+    assert_eq!(
+        code_actions.as_slice(),
+        &["Use ===", "Suppress rule correctness/noDoubleEquals"]
+    );
 
     server.close_document().await?;
 
@@ -1,7 +1,10 @@
 use rome_text_edit::TextEdit;
 use rome_text_size::TextRange;
 
-use crate::{AstNode, Language, SyntaxElement, SyntaxKind, SyntaxNode, SyntaxSlot, SyntaxToken};
+use crate::{
+    AstNode, Language, SyntaxElement, SyntaxKind, SyntaxNode, SyntaxSlot, SyntaxToken,
+    TriviaPieceKind,
+};
 use std::{
     cmp,
     collections::BinaryHeap,
@@ -101,6 +104,11 @@ where
         }
     }
 
+    /// Returns the root of the tree this mutation applies to
+    pub fn root(&self) -> &SyntaxNode<L> {
+        &self.root
+    }
+
     /// Push a change to replace the "prev_node" with "next_node".
     /// Trivia from "prev_node" is automatically copied to "next_node".
     ///
@@ -248,6 +256,154 @@ where
         self.push_change(prev_element, None)
     }
 
+    /// Push all the changes of "other" to this mutation.
+    ///
+    /// Both mutations must apply to the same root, and their changes must not replace
+    /// overlapping elements.
+    ///
+    /// Changes to take effect must be commited.
+    pub fn merge(&mut self, other: BatchMutation<L>) {
+        debug_assert_eq!(
+            self.root, other.root,
+            "Can't merge the changes of a mutation of another tree"
+        );
+
+        self.changes.extend(other.changes);
+    }
+
+    /// Push the changes to move the comments attached to "from_node" over to "to_node".
+    ///
+    /// The comments in the leading trivia of the first token of "from_node" are appended to the
+    /// leading trivia of the first token of "to_node", and the comments in the trailing trivia of
+    /// the last token of "from_node" are appended to the trailing trivia of the last token of
+    /// "to_node". The formatter attaches comments to nodes based on the trivia of their tokens,
+    /// so formatting the committed tree places the moved comments at their new location.
+    /// If the moved trailing comments end with a line comment, a line break is inserted before
+    /// the token following "to_node" so that the comment doesn't swallow it.
+    ///
+    /// Returns `false`, without pushing any change, if "from_node" has no comments
+    /// to move or if the two nodes overlap.
+    ///
+    /// The tokens of both nodes must not be changed by another change of this mutation.
+    ///
+    /// Changes to take effect must be commited.
+    pub fn move_comments<F, T>(&mut self, from_node: &F, to_node: &T) -> bool
+    where
+        F: AstNode<Language = L>,
+        T: AstNode<Language = L>,
+    {
+        let from = from_node.syntax();
+        let to = to_node.syntax();
+
+        let from_range = from.text_range();
+        let to_range = to.text_range();
+        if from_range.end() > to_range.start() && to_range.end() > from_range.start() {
+            return false;
+        }
+
+        let (from_first, from_last, to_first, to_last) = match (
+            from.first_token(),
+            from.last_token(),
+            to.first_token(),
+            to.last_token(),
+        ) {
+            (Some(from_first), Some(from_last), Some(to_first), Some(to_last)) => {
+                (from_first, from_last, to_first, to_last)
+            }
+            _ => return false,
+        };
+
+        let leading: Vec<_> = from_first.leading_trivia().pieces().collect();
+        let leading_start = leading.iter().position(|piece| piece.is_comments());
+
+        let trailing: Vec<_> = from_last.trailing_trivia().pieces().collect();
+        let trailing_start = trailing.iter().position(|piece| piece.is_comments());
+        // Moves the whitespace separating the comments from the token together with the comments
+        let trailing_start = trailing_start.map(|mut start| {
+            while start > 0 && trailing[start - 1].is_whitespace() {
+                start -= 1;
+            }
+            start
+        });
+
+        if leading_start.is_none() && trailing_start.is_none() {
+            return false;
+        }
+
+        // The first and last token of a node can be the same token, so all the updates
+        // to a token are accumulated before pushing a single change for it
+        let mut replacements: Vec<(SyntaxToken<L>, SyntaxToken<L>)> = Vec::with_capacity(4);
+        let mut update = |token: SyntaxToken<L>, f: &dyn Fn(&SyntaxToken<L>) -> SyntaxToken<L>| {
+            match replacements.iter_mut().find(|(prev, _)| *prev == token) {
+                Some((_, next)) => *next = f(next),
+                None => {
+                    let next = f(&token);
+                    replacements.push((token, next));
+                }
+            }
+        };
+
+        if let Some(start) = leading_start {
+            let (kept, moved) = leading.split_at(start);
+
+            update(from_first, &|token| {
+                token.with_leading_trivia_pieces(kept.iter().cloned())
+            });
+            update(to_first, &|token| {
+                let pieces: Vec<_> = token
+                    .leading_trivia()
+                    .pieces()
+                    .chain(moved.iter().cloned())
+                    .collect();
+                token.with_leading_trivia_pieces(pieces)
+            });
+        }
+
+        if let Some(start) = trailing_start {
+            let (kept, moved) = trailing.split_at(start);
+
+            update(from_last, &|token| {
+                token.with_trailing_trivia_pieces(kept.iter().cloned())
+            });
+            update(to_last.clone(), &|token| {
+                let pieces: Vec<_> = token
+                    .trailing_trivia()
+                    .pieces()
+                    .chain(moved.iter().cloned())
+                    .collect();
+                token.with_trailing_trivia_pieces(pieces)
+            });
+
+            // A line comment extends to the end of the line: break the line after it so that
+            // it doesn't comment out the code following "to_node" on the same line
+            let ends_with_line_comment = moved
+                .last()
+                .map_or(false, |piece| piece.kind().is_single_line_comment());
+
+            if ends_with_line_comment {
+                if let Some(next_token) = to_last.next_token() {
+                    update(next_token, &|token| {
+                        let pieces: Vec<_> = token.leading_trivia().pieces().collect();
+                        if pieces.first().map_or(false, |piece| piece.is_newline()) {
+                            return token.clone();
+                        }
+
+                        let trivia: Vec<_> = std::iter::once((TriviaPieceKind::Newline, "\n"))
+                            .chain(pieces.iter().map(|piece| (piece.kind(), piece.text())))
+                            .collect();
+                        token.with_leading_trivia(trivia)
+                    });
+                }
+            }
+        }
+
+        for (prev_token, next_token) in replacements {
+            self.replace_element_discard_trivia(prev_token.into(), next_token.into());
+        }
+
+        true
+    }
+
     fn push_change(
         &mut self,
         prev_element: SyntaxElement<L>,
@@ -277,11 +433,9 @@ where
         let mut range = None;
 
         for change in &self.changes {
-            let parent = change.parent.as_ref().unwrap_or(&self.root);
-            let delete = match parent.slots().nth(change.new_node_slot) {
-                Some(SyntaxSlot::Node(node)) => node.text_range(),
-                Some(SyntaxSlot::Token(token)) => token.text_range(),
-                _ => continue,
+            let delete = match self.change_range(change) {
+                Some(delete) => delete,
+                None => continue,
             };
 
             range = match range {
@@ -299,6 +453,43 @@ where
         Some((text_range, text_edit))
     }
 
+    /// Returns the disjoint ranges of the document modified by this mutation, sorted
+    /// by their start offset. The overlapping and adjacent ranges are merged together.
+    ///
+    /// Unlike the range returned by [BatchMutation::as_text_edits] that covers all the
+    /// changes, the ranges don't include the unchanged text between two changes.
+    pub fn text_ranges(&self) -> Vec<TextRange> {
+        let mut ranges: Vec<_> = self
+            .changes
+            .iter()
+            .filter_map(|change| self.change_range(change))
+            .collect();
+
+        ranges.sort_by_key(|range| range.start());
+
+        let mut merged: Vec<TextRange> = Vec::with_capacity(ranges.len());
+
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start() <= last.end() => *last = last.cover(range),
+                _ => merged.push(range),
+            }
+        }
+
+        merged
+    }
+
+    /// Returns the range of the element replaced by `change`, or [None] if it fills an empty slot
+    fn change_range(&self, change: &CommitChange<L>) -> Option<TextRange> {
+        let parent = change.parent.as_ref().unwrap_or(&self.root);
+
+        match parent.slots().nth(change.new_node_slot) {
+            Some(SyntaxSlot::Node(node)) => Some(node.text_range()),
+            Some(SyntaxSlot::Token(token)) => Some(token.text_range()),
+            _ => None,
+        }
+    }
+
     /// The core of the batch mutation algorithm can be summarized as:
     /// 1 - Iterate all requested changes;
     /// 2 - Insert them into a heap (priority queue) by depth. Deeper changes are done first;
@@ -395,7 +586,7 @@ where
 pub mod tests {
     use crate::{
         raw_language::{LiteralExpression, RawLanguageKind, RawLanguageRoot, RawSyntaxTreeBuilder},
-        AstNode, BatchMutationExt, SyntaxNodeCast,
+        AstNode, BatchMutationExt, SyntaxNodeCast, TextRange, TextSize, TriviaPiece,
     };
 
     /// ```
@@ -500,4 +691,230 @@ pub mod tests {
 
         assert_eq!(expected_debug, format!("{:#?}", after));
     }
+
+    /// Creates a tree with a literal expression for each name, separated by a space
+    fn tree_spaced(names: &[&str]) -> RawLanguageRoot {
+        let mut builder = RawSyntaxTreeBuilder::new();
+        builder.start_node(RawLanguageKind::ROOT);
+
+        for (index, name) in names.iter().enumerate() {
+            builder.start_node(RawLanguageKind::LITERAL_EXPRESSION);
+            if index == 0 {
+                builder.token(RawLanguageKind::STRING_TOKEN, name);
+            } else {
+                builder.token_with_trivia(
+                    RawLanguageKind::STRING_TOKEN,
+                    &format!(" {name}"),
+                    &[TriviaPiece::whitespace(1)],
+                    &[],
+                );
+            }
+            builder.finish_node();
+        }
+
+        builder.finish_node();
+        builder.finish().cast::<RawLanguageRoot>().unwrap()
+    }
+
+    #[test]
+    pub fn ok_batch_mutation_text_ranges_one_change() {
+        let before = tree_spaced(&["a", "b"]);
+
+        let mut batch = before.clone().begin();
+        batch.remove_node(find(&before, "b"));
+
+        assert_eq!(
+            batch.text_ranges(),
+            [TextRange::new(TextSize::from(1), TextSize::from(3))]
+        );
+    }
+
+    #[test]
+    pub fn ok_batch_mutation_text_ranges_two_changes() {
+        let before = tree_spaced(&["a", "b", "c"]);
+
+        let mut batch = before.clone().begin();
+        batch.remove_node(find(&before, "c"));
+        batch.remove_node(find(&before, "a"));
+
+        assert_eq!(
+            batch.text_ranges(),
+            [
+                TextRange::new(TextSize::from(0), TextSize::from(1)),
+                TextRange::new(TextSize::from(3), TextSize::from(5)),
+            ]
+        );
+
+        let (range, _) = batch.as_text_edits().unwrap();
+        assert_eq!(range, TextRange::new(TextSize::from(0), TextSize::from(5)));
+    }
+
+    #[test]
+    pub fn ok_batch_mutation_text_ranges_three_changes() {
+        let before = tree_spaced(&["a", "b", "c", "d", "e"]);
+
+        let mut batch = before.clone().begin();
+        batch.remove_node(find(&before, "e"));
+        batch.remove_node(find(&before, "a"));
+        batch.remove_node(find(&before, "c"));
+
+        assert_eq!(
+            batch.text_ranges(),
+            [
+                TextRange::new(TextSize::from(0), TextSize::from(1)),
+                TextRange::new(TextSize::from(3), TextSize::from(5)),
+                TextRange::new(TextSize::from(7), TextSize::from(9)),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn ok_batch_mutation_text_ranges_merge_adjacent() {
+        let before = tree_spaced(&["a", "b", "c"]);
+
+        let mut batch = before.clone().begin();
+        batch.remove_node(find(&before, "b"));
+        batch.remove_node(find(&before, "a"));
+
+        assert_eq!(
+            batch.text_ranges(),
+            [TextRange::new(TextSize::from(0), TextSize::from(3))]
+        );
+    }
+
+    #[test]
+    pub fn ok_batch_mutation_merge() {
+        let before = tree_spaced(&["a", "b", "c"]);
+
+        let mut batch = before.clone().begin();
+        batch.remove_node(find(&before, "c"));
+
+        let mut other = before.clone().begin();
+        other.remove_node(find(&before, "a"));
+
+        batch.merge(other);
+
+        assert_eq!(
+            batch.text_ranges(),
+            [
+                TextRange::new(TextSize::from(0), TextSize::from(1)),
+                TextRange::new(TextSize::from(3), TextSize::from(5)),
+            ]
+        );
+        assert_eq!(batch.commit().to_string(), " b");
+    }
+
+    /// ```
+    /// 0: ROOT@0..15
+    ///     0: LITERAL_EXPRESSION@0..13
+    ///         0: STRING_TOKEN@0..13 "a" [Comments("/*x*/"), Whitespace(" ")] [Whitespace(" "), Comments("/*y*/")]
+    ///     1: LITERAL_EXPRESSION@13..15
+    ///         0: STRING_TOKEN@13..15 "b" [Whitespace(" ")] []
+    /// ```
+    fn tree_with_comments() -> RawLanguageRoot {
+        let mut builder = RawSyntaxTreeBuilder::new();
+        builder
+            .start_node(RawLanguageKind::ROOT)
+            .start_node(RawLanguageKind::LITERAL_EXPRESSION);
+        builder.token_with_trivia(
+            RawLanguageKind::STRING_TOKEN,
+            "/*x*/ a /*y*/",
+            &[
+                TriviaPiece::multi_line_comment(5),
+                TriviaPiece::whitespace(1),
+            ],
+            &[
+                TriviaPiece::whitespace(1),
+                TriviaPiece::multi_line_comment(5),
+            ],
+        );
+        builder
+            .finish_node()
+            .start_node(RawLanguageKind::LITERAL_EXPRESSION);
+        builder.token_with_trivia(
+            RawLanguageKind::STRING_TOKEN,
+            " b",
+            &[TriviaPiece::whitespace(1)],
+            &[],
+        );
+        builder.finish_node().finish_node();
+        builder.finish().cast::<RawLanguageRoot>().unwrap()
+    }
+
+    #[test]
+    pub fn ok_batch_mutation_move_comments() {
+        let before = tree_with_comments();
+
+        let a = find(&before, "a");
+        let b = find(&before, "b");
+
+        let mut batch = before.begin();
+        assert!(batch.move_comments(&a, &b));
+        let after = batch.commit();
+
+        assert_eq!(after.to_string(), "a /*x*/ b /*y*/");
+    }
+
+    #[test]
+    pub fn ok_batch_mutation_move_line_comment() {
+        // a // x
+        // b c
+        let mut builder = RawSyntaxTreeBuilder::new();
+        builder
+            .start_node(RawLanguageKind::ROOT)
+            .start_node(RawLanguageKind::LITERAL_EXPRESSION);
+        builder.token_with_trivia(
+            RawLanguageKind::STRING_TOKEN,
+            "a // x",
+            &[],
+            &[
+                TriviaPiece::whitespace(1),
+                TriviaPiece::single_line_comment(4),
+            ],
+        );
+        builder
+            .finish_node()
+            .start_node(RawLanguageKind::LITERAL_EXPRESSION);
+        builder.token_with_trivia(
+            RawLanguageKind::STRING_TOKEN,
+            "\nb",
+            &[TriviaPiece::newline(1)],
+            &[],
+        );
+        builder
+            .finish_node()
+            .start_node(RawLanguageKind::LITERAL_EXPRESSION);
+        builder.token_with_trivia(
+            RawLanguageKind::STRING_TOKEN,
+            " c",
+            &[TriviaPiece::whitespace(1)],
+            &[],
+        );
+        builder.finish_node().finish_node();
+        let before = builder.finish().cast::<RawLanguageRoot>().unwrap();
+
+        let a = find(&before, "a");
+        let b = find(&before, "b");
+
+        let mut batch = before.begin();
+        assert!(batch.move_comments(&a, &b));
+        let after = batch.commit();
+
+        assert_eq!(after.to_string(), "a\nb // x\n c");
+    }
+
+    #[test]
+    pub fn ok_batch_mutation_move_comments_without_comments() {
+        let before = tree_with_comments();
+
+        let a = find(&before, "a");
+        let b = find(&before, "b");
+
+        let mut batch = before.begin();
+        assert!(!batch.move_comments(&b, &a));
+        assert!(!batch.move_comments(&a, &a));
+        let after = batch.commit();
+
+        assert_eq!(after.to_string(), "/*x*/ a /*y*/ b");
+    }
 }
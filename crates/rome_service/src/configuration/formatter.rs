@@ -15,7 +15,7 @@ pub struct FormatterConfiguration {
     /// has syntax errors
     pub format_with_errors: bool,
 
-    /// The indent style.
+    /// The indent style. `auto` detects the indentation of each file.
     pub indent_style: PlainIndentStyle,
 
     /// The size of the indentation, 2 by default
@@ -57,7 +57,10 @@ impl TryFrom<FormatterConfiguration> for FormatSettings {
     fn try_from(conf: FormatterConfiguration) -> Result<Self, Self::Error> {
         let indent_style = match conf.indent_style {
             PlainIndentStyle::Tab => IndentStyle::Tab,
-            PlainIndentStyle::Space => IndentStyle::Space(conf.indent_size),
+            // `auto` uses spaces for the files without any indented line to detect from
+            PlainIndentStyle::Space | PlainIndentStyle::Auto => {
+                IndentStyle::Space(conf.indent_size)
+            }
         };
         let mut matcher = Matcher::new(MatchOptions {
             case_sensitive: true,
@@ -77,6 +80,7 @@ impl TryFrom<FormatterConfiguration> for FormatSettings {
         Ok(Self {
             enabled: conf.enabled,
             indent_style: Some(indent_style),
+            detect_indent_style: conf.indent_style == PlainIndentStyle::Auto,
             line_width: Some(conf.line_width),
             format_with_errors: conf.format_with_errors,
             ignored_files: matcher,
@@ -108,4 +112,6 @@ pub enum PlainIndentStyle {
     Tab,
     /// Space
     Space,
+    /// Detect the indentation of each file, defaulting to `indentSize` spaces
+    Auto,
 }
@@ -31,6 +31,16 @@ pub struct LinterConfiguration {
         serialize_with = "crate::serialize_set_of_strings"
     )]
     pub ignore: Option<IndexSet<String>>,
+
+    /// A list of Unix shell style patterns matching the test files, where some rules are relaxed.
+    /// Defaults to `*.test.*`, `*.spec.*` and the files inside the `__tests__` and `__mocks__`
+    /// directories.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserialize_set_of_strings",
+        serialize_with = "crate::serialize_set_of_strings"
+    )]
+    pub test_files: Option<IndexSet<String>>,
 }
 
 impl Default for LinterConfiguration {
@@ -39,6 +49,7 @@ impl Default for LinterConfiguration {
             enabled: true,
             rules: Some(Rules::default()),
             ignore: None,
+            test_files: None,
         }
     }
 }
@@ -62,10 +73,32 @@ impl TryFrom<LinterConfiguration> for LinterSettings {
                 })?;
             }
         }
+
+        let test_files = match conf.test_files {
+            Some(test_files) => {
+                let mut test_matcher = Matcher::new(MatchOptions {
+                    case_sensitive: true,
+                    require_literal_leading_dot: false,
+                    require_literal_separator: false,
+                });
+                for pattern in test_files {
+                    test_matcher.add_pattern(&pattern).map_err(|err| {
+                        RomeError::Configuration(ConfigurationError::InvalidTestFilesPattern(
+                            pattern.to_string(),
+                            err.msg.to_string(),
+                        ))
+                    })?;
+                }
+                test_matcher
+            }
+            None => LinterSettings::default_test_files(),
+        };
+
         Ok(Self {
             enabled: conf.enabled,
             rules: conf.rules,
             ignored_files: matcher,
+            test_files,
         })
     }
 }
@@ -691,19 +691,23 @@ struct NurserySchema {
     no_const_assign: Option<RuleConfiguration>,
     no_explicit_any: Option<RuleConfiguration>,
     no_invalid_constructor_super: Option<RuleConfiguration>,
+    no_missing_initializer: Option<RuleConfiguration>,
     use_camel_case: Option<RuleConfiguration>,
+    use_const: Option<RuleConfiguration>,
     use_exhaustive_dependencies: Option<RuleConfiguration>,
     use_flat_map: Option<RuleConfiguration>,
     use_valid_for_direction: Option<RuleConfiguration>,
 }
 impl Nursery {
     const CATEGORY_NAME: &'static str = "nursery";
-    pub(crate) const CATEGORY_RULES: [&'static str; 8] = [
+    pub(crate) const CATEGORY_RULES: [&'static str; 10] = [
         "noBannedTypes",
         "noConstAssign",
         "noExplicitAny",
         "noInvalidConstructorSuper",
+        "noMissingInitializer",
         "useCamelCase",
+        "useConst",
         "useExhaustiveDependencies",
         "useFlatMap",
         "useValidForDirection",
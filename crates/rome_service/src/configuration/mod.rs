@@ -102,6 +102,9 @@ pub enum ConfigurationError {
 
     /// Thrown when the pattern inside the `ignore` field errors
     InvalidIgnorePattern(String, String),
+
+    /// Thrown when the pattern inside the `testFiles` field errors
+    InvalidTestFilesPattern(String, String),
 }
 
 impl Debug for ConfigurationError {
@@ -112,6 +115,7 @@ impl Debug for ConfigurationError {
             ConfigurationError::ConfigAlreadyExists => std::fmt::Display::fmt(self, f),
             ConfigurationError::UnknownRule(_) => std::fmt::Display::fmt(self, f),
             ConfigurationError::InvalidIgnorePattern(_, _) => std::fmt::Display::fmt(self, f),
+            ConfigurationError::InvalidTestFilesPattern(_, _) => std::fmt::Display::fmt(self, f),
         }
     }
 }
@@ -142,6 +146,12 @@ impl Display for ConfigurationError {
             ConfigurationError::InvalidIgnorePattern(pattern, reason) => {
                 write!(f, "couldn't parse the pattern {pattern}, reason: {reason}")
             }
+            ConfigurationError::InvalidTestFilesPattern(pattern, reason) => {
+                write!(
+                    f,
+                    "couldn't parse the test files pattern {pattern}, reason: {reason}"
+                )
+            }
         }
     }
 }
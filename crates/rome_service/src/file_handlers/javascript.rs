@@ -7,13 +7,16 @@ use crate::{
     RomeError, Rules,
 };
 use rome_analyze::{
-    AnalysisFilter, AnalyzerOptions, ControlFlow, GroupCategory, Never, QueryMatch,
-    RegistryVisitor, RuleCategories, RuleCategory, RuleFilter, RuleGroup,
+    ActionCategory, AnalysisFilter, AnalyzerAction, AnalyzerActionIter, AnalyzerOptions,
+    ControlFlow, FixReport, GroupCategory, Never, QueryMatch, RegistryVisitor, RuleCategories,
+    RuleCategory, RuleFilter, RuleGroup,
 };
-use rome_diagnostics::{Applicability, CodeSuggestion};
-use rome_formatter::{FormatError, Printed};
+use rome_diagnostics::{file::FileId, Applicability, CodeSuggestion};
+use rome_formatter::{detect_indent_style, FormatError, FormatOptions, Printed};
 use rome_fs::RomePath;
-use rome_js_analyze::{analyze, analyze_with_inspect_matcher, visit_registry, RuleError};
+use rome_js_analyze::{
+    analyze, analyze_with_inspect_matcher, analyze_with_summary, visit_registry, RuleError,
+};
 use rome_js_formatter::context::{trailing_comma::TrailingComma, QuoteProperties, QuoteStyle};
 use rome_js_formatter::{context::JsFormatOptions, format_node};
 use rome_js_parser::Parse;
@@ -33,6 +36,7 @@ use indexmap::IndexSet;
 use rome_diagnostics::{v2, v2::Diagnostic};
 use rome_js_analyze::utils::rename::{RenameError, RenameSymbolExtensions};
 use std::borrow::Cow;
+use std::collections::HashMap;
 use std::fmt::Debug;
 use tracing::debug;
 
@@ -42,6 +46,8 @@ pub struct JsFormatSettings {
     pub quote_style: Option<QuoteStyle>,
     pub quote_properties: Option<QuoteProperties>,
     pub trailing_comma: Option<TrailingComma>,
+    /// The maximum nesting depth of the formatted nodes, 512 by default
+    pub max_depth: Option<u32>,
 }
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -64,12 +70,17 @@ impl Language for JsLanguage {
         language: &JsFormatSettings,
         path: &RomePath,
     ) -> JsFormatOptions {
-        JsFormatOptions::new(path.as_path().try_into().unwrap_or_default())
+        let options = JsFormatOptions::new(path.as_path().try_into().unwrap_or_default())
             .with_indent_style(global.indent_style.unwrap_or_default())
             .with_line_width(global.line_width.unwrap_or_default())
             .with_quote_style(language.quote_style.unwrap_or_default())
             .with_quote_properties(language.quote_properties.unwrap_or_default())
-            .with_trailing_comma(language.trailing_comma.unwrap_or_default())
+            .with_trailing_comma(language.trailing_comma.unwrap_or_default());
+
+        match language.max_depth {
+            Some(max_depth) => options.with_max_depth(max_depth),
+            None => options,
+        }
     }
 }
 
@@ -90,6 +101,7 @@ impl ExtensionHandler for JsFileHandler {
                 code_actions: Some(code_actions),
                 fix_all: Some(fix_all),
                 rename: Some(rename),
+                fix_report: Some(fix_report),
             },
             formatter: FormatterCapabilities {
                 format: Some(format),
@@ -200,9 +212,8 @@ fn debug_formatter_ir(
     parse: AnyParse,
     settings: SettingsHandle,
 ) -> Result<String, RomeError> {
-    let options = settings.format_options::<JsLanguage>(rome_path);
-
     let tree = parse.syntax();
+    let options = format_options(rome_path, &tree, settings);
     let formatted = format_node(options, &tree)?;
 
     let root_element = formatted.into_document();
@@ -214,42 +225,44 @@ fn lint(params: LintParams) -> LintResults {
     let mut diagnostics = params.parse.into_diagnostics();
 
     let file_id = params.rome_path.file_id();
-    let analyzer_options = compute_analyzer_options(&params.settings);
+    let analyzer_options = compute_analyzer_options(&params.settings, params.rome_path);
 
     let mut diagnostic_count = diagnostics.len() as u64;
     let mut has_errors = diagnostics
         .iter()
         .any(|diag| diag.severity() <= v2::Severity::Error);
 
-    analyze(file_id, &tree, params.filter, &analyzer_options, |signal| {
-        if let Some(mut diagnostic) = signal.diagnostic() {
-            diagnostic_count += 1;
+    let (_, summary) =
+        analyze_with_summary(file_id, &tree, params.filter, &analyzer_options, |signal| {
+            if let Some(mut diagnostic) = signal.diagnostic() {
+                diagnostic_count += 1;
+
+                // We do now check if the severity of the diagnostics should be changed.
+                // The configuration allows to change the severity of the diagnostics
+                // emitted by rules.
+                let severity = diagnostic
+                    .category()
+                    .filter(|category| category.name().starts_with("lint/"))
+                    .and_then(|category| params.rules.as_ref()?.get_severity_from_code(category))
+                    .unwrap_or(v2::Severity::Error);
+
+                if severity <= v2::Severity::Error {
+                    has_errors = true;
+                }
 
-            // We do now check if the severity of the diagnostics should be changed.
-            // The configuration allows to change the severity of the diagnostics emitted by rules.
-            let severity = diagnostic
-                .category()
-                .filter(|category| category.name().starts_with("lint/"))
-                .and_then(|category| params.rules.as_ref()?.get_severity_from_code(category))
-                .unwrap_or(v2::Severity::Error);
+                if diagnostic_count <= params.max_diagnostics {
+                    diagnostic.set_severity(severity);
 
-            if severity <= v2::Severity::Error {
-                has_errors = true;
-            }
-
-            if diagnostic_count <= params.max_diagnostics {
-                diagnostic.set_severity(severity);
+                    if let Some(action) = signal.action() {
+                        diagnostic.add_code_suggestion(action.into());
+                    }
 
-                if let Some(action) = signal.action() {
-                    diagnostic.add_code_suggestion(action.into());
+                    diagnostics.push(v2::serde::Diagnostic::new(diagnostic));
                 }
-
-                diagnostics.push(v2::serde::Diagnostic::new(diagnostic));
             }
-        }
 
-        ControlFlow::<Never>::Continue(())
-    });
+            ControlFlow::<Never>::Continue(())
+        });
 
     let skipped_diagnostics = diagnostic_count - diagnostics.len() as u64;
 
@@ -257,6 +270,7 @@ fn lint(params: LintParams) -> LintResults {
         diagnostics,
         has_errors,
         skipped_diagnostics,
+        suppressed_diagnostics: summary.suppressed as u64,
     }
 }
 
@@ -322,10 +336,11 @@ fn code_actions(
 
     let file_id = rome_path.file_id();
 
-    let analyzer_options = compute_analyzer_options(&settings);
+    let analyzer_options = compute_analyzer_options(&settings, rome_path);
 
     analyze(file_id, &tree, filter, &analyzer_options, |signal| {
-        if let Some(action) = signal.action() {
+        // Includes the actions suppressing the diagnostic of the signal
+        for action in signal.actions() {
             actions.push(CodeAction {
                 category: action.category,
                 rule_name: Cow::Borrowed(action.rule_name),
@@ -339,6 +354,52 @@ fn code_actions(
     PullActionsResult { actions }
 }
 
+/// Reports the diagnostics emitted by the syntax and lint rules along with the
+/// fixes proposed by their actions, the suppression actions excluded
+fn fix_report(
+    rome_path: &RomePath,
+    parse: AnyParse,
+    rules: Option<&Rules>,
+    settings: SettingsHandle,
+) -> FixReport {
+    let tree = parse.tree();
+
+    let enabled_rules: Option<Vec<RuleFilter>> =
+        rules.map(|rules| rules.as_enabled_rules().into_iter().collect());
+
+    let mut filter = match &enabled_rules {
+        Some(rules) => AnalysisFilter::from_enabled_rules(Some(rules.as_slice())),
+        _ => AnalysisFilter::default(),
+    };
+
+    filter.categories = RuleCategories::SYNTAX | RuleCategories::LINT;
+
+    let file_id = rome_path.file_id();
+    let analyzer_options = compute_analyzer_options(&settings, rome_path);
+
+    let mut report = FixReport::default();
+
+    analyze(file_id, &tree, filter, &analyzer_options, |signal| {
+        if let Some(diagnostic) = signal.diagnostic() {
+            let fixes = signal
+                .actions()
+                .filter(|action| {
+                    !matches!(
+                        action.category,
+                        ActionCategory::Suppression | ActionCategory::FileSuppression
+                    )
+                })
+                .collect();
+
+            report.add(&diagnostic, AnalyzerActionIter::new(fixes));
+        }
+
+        ControlFlow::<Never>::Continue(())
+    });
+
+    report
+}
+
 /// If applies all the safe fixes to the given syntax tree.
 ///
 /// If `indent_style` is [Some], it means that the formatting should be applied at the end
@@ -368,18 +429,33 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, RomeError> {
 
     filter.categories = RuleCategories::SYNTAX | RuleCategories::LINT;
     let file_id = rome_path.file_id();
+    let analyzer_options = compute_analyzer_options(&settings, rome_path);
+    let format_options = format_options(rome_path, tree.syntax(), settings);
+    let applicability = match fix_file_mode {
+        FixFileMode::SafeFixes => Applicability::Always,
+        FixFileMode::SafeAndSuggestedFixes => Applicability::MaybeIncorrect,
+    };
+
     let mut skipped_suggested_fixes = 0;
-    let analyzer_options = compute_analyzer_options(&settings);
     loop {
-        let action = analyze(file_id, &tree, filter, &analyzer_options, |signal| {
-            if let Some(action) = signal.action() {
+        let mut candidates = Vec::new();
+
+        analyze(file_id, &tree, filter, &analyzer_options, |signal| {
+            for action in signal.actions() {
+                if matches!(
+                    action.category,
+                    ActionCategory::Suppression | ActionCategory::FileSuppression
+                ) {
+                    continue;
+                }
+
                 match fix_file_mode {
                     FixFileMode::SafeFixes => {
                         if action.applicability == Applicability::MaybeIncorrect {
                             skipped_suggested_fixes += 1;
                         }
                         if action.applicability == Applicability::Always {
-                            return ControlFlow::Break(action);
+                            candidates.push(action);
                         }
                     }
                     FixFileMode::SafeAndSuggestedFixes => {
@@ -387,34 +463,46 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, RomeError> {
                             action.applicability,
                             Applicability::Always | Applicability::MaybeIncorrect
                         ) {
-                            return ControlFlow::Break(action);
+                            candidates.push(action);
                         }
                     }
                 }
             }
 
-            ControlFlow::Continue(())
+            ControlFlow::<Never>::Continue(())
         });
 
-        match action {
-            Some(action) => {
-                if let Some((range, _)) = action.mutation.as_text_edits() {
-                    tree = match JsAnyRoot::cast(action.mutation.commit()) {
-                        Some(tree) => tree,
-                        None => {
-                            return Err(RomeError::RuleError(
-                                RuleError::ReplacedRootWithNonRootError {
-                                    rule_name: Cow::Borrowed(action.rule_name),
-                                },
-                            ))
-                        }
-                    };
-                    actions.push(FixAction {
-                        rule_name: Cow::Borrowed(action.rule_name),
-                        range,
-                    });
+        // The fixes of the rules matching the same range are alternatives of each other,
+        // only the preferred one is applied
+        let mut alternatives: Vec<Vec<AnalyzerAction<JsLanguage>>> = Vec::new();
+        let mut alternatives_by_span = HashMap::new();
+
+        for candidate in candidates {
+            let index = match candidate.span {
+                Some(span) => *alternatives_by_span.entry(span).or_insert_with(|| {
+                    alternatives.push(Vec::new());
+                    alternatives.len() - 1
+                }),
+                None => {
+                    alternatives.push(Vec::new());
+                    alternatives.len() - 1
                 }
-            }
+            };
+
+            alternatives[index].push(candidate);
+        }
+
+        let fixes = alternatives
+            .into_iter()
+            .filter_map(|candidates| preferred_action(candidates, file_id, &format_options))
+            .collect();
+
+        // The fixes conflicting with a previous fix are computed again on the updated tree
+        let result = AnalyzerActionIter::new(fixes)
+            .apply_non_conflicting(tree.syntax().clone(), applicability);
+
+        let first_fix = match result.applied.first() {
+            Some(fix) => fix,
             None => {
                 return Ok(FixFileResult {
                     code: tree.syntax().to_string(),
@@ -422,7 +510,76 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, RomeError> {
                     actions,
                 });
             }
-        }
+        };
+
+        tree = match JsAnyRoot::cast(result.root) {
+            Some(tree) => tree,
+            None => {
+                return Err(RomeError::RuleError(
+                    RuleError::ReplacedRootWithNonRootError {
+                        rule_name: Cow::Borrowed(first_fix.rule_name),
+                    },
+                ))
+            }
+        };
+
+        actions.extend(result.applied.into_iter().map(|fix| FixAction {
+            rule_name: Cow::Borrowed(fix.rule_name),
+            range: fix.range,
+        }));
+    }
+}
+
+/// Returns the action producing the shortest formatted output among
+/// `candidates`, a set of alternative fixes of different rules for the same range.
+///
+/// When there are several candidates, each of them is applied, re-parsed and
+/// formatted: candidates producing invalid syntax or failing to format are
+/// discarded. The first candidate wins if several produce outputs of the same
+/// length.
+fn preferred_action(
+    candidates: Vec<AnalyzerAction<JsLanguage>>,
+    file_id: FileId,
+    options: &JsFormatOptions,
+) -> Option<AnalyzerAction<JsLanguage>> {
+    if candidates.len() <= 1 {
+        return candidates.into_iter().next();
+    }
+
+    candidates
+        .into_iter()
+        .filter_map(|action| {
+            let code = action.mutation.clone().commit().to_string();
+
+            let parse = rome_js_parser::parse(&code, file_id, options.source_type());
+            if parse.has_errors() {
+                return None;
+            }
+
+            let formatted = format_node(options.clone(), &parse.syntax()).ok()?;
+            let printed = formatted.print().ok()?;
+
+            Some((printed.as_code().len(), action))
+        })
+        .min_by_key(|(len, _)| *len)
+        .map(|(_, action)| action)
+}
+
+/// Resolves the format options of the file at `rome_path`, using the indent style
+/// detected from `tree` if the formatter settings enable the detection
+fn format_options(
+    rome_path: &RomePath,
+    tree: &JsSyntaxNode,
+    settings: SettingsHandle,
+) -> JsFormatOptions {
+    let detect = settings.as_ref().formatter().detect_indent_style;
+    let options = settings.format_options::<JsLanguage>(rome_path);
+
+    if detect {
+        let indent_style = detect_indent_style(tree, options.indent_style());
+        options.with_indent_style(indent_style)
+    } else {
+        options
     }
 }
 
@@ -432,11 +589,11 @@ fn format(
     parse: AnyParse,
     settings: SettingsHandle,
 ) -> Result<Printed, RomeError> {
-    let options = settings.format_options::<JsLanguage>(rome_path);
+    let tree = parse.syntax();
+    let options = format_options(rome_path, &tree, settings);
 
     debug!("Format with the following options: \n{}", options);
 
-    let tree = parse.syntax();
     let formatted = format_node(options, &tree)?;
 
     match formatted.print() {
@@ -451,9 +608,9 @@ fn format_range(
     settings: SettingsHandle,
     range: TextRange,
 ) -> Result<Printed, RomeError> {
-    let options = settings.format_options::<JsLanguage>(rome_path);
-
     let tree = parse.syntax();
+    let options = format_options(rome_path, &tree, settings);
+
     let printed = rome_js_formatter::format_range(options, &tree, range)?;
     Ok(printed)
 }
@@ -464,9 +621,8 @@ fn format_on_type(
     settings: SettingsHandle,
     offset: TextSize,
 ) -> Result<Printed, RomeError> {
-    let options = settings.format_options::<JsLanguage>(rome_path);
-
     let tree = parse.syntax();
+    let options = format_options(rome_path, &tree, settings);
 
     let range = tree.text_range();
     if offset < range.start() || offset > range.end() {
@@ -531,7 +687,7 @@ fn rename(
     }
 }
 
-fn compute_analyzer_options(settings: &SettingsHandle) -> AnalyzerOptions {
+fn compute_analyzer_options(settings: &SettingsHandle, rome_path: &RomePath) -> AnalyzerOptions {
     let configuration = to_analyzer_configuration(
         settings.as_ref().linter(),
         &settings.as_ref().languages,
@@ -546,5 +702,141 @@ fn compute_analyzer_options(settings: &SettingsHandle) -> AnalyzerOptions {
             }
         },
     );
-    AnalyzerOptions { configuration }
+    AnalyzerOptions {
+        configuration,
+        file_kind: settings.as_ref().linter().file_kind(rome_path),
+        ..AnalyzerOptions::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rome_analyze::{ActionCategory, AnalyzerAction};
+    use rome_console::markup;
+    use rome_diagnostics::{file::FileId, Applicability};
+    use rome_fs::RomePath;
+    use rome_js_formatter::context::JsFormatOptions;
+    use rome_js_syntax::{
+        JsAnyRoot, JsLanguage, JsSyntaxKind, JsSyntaxToken, SourceType, TextRange, TextSize,
+    };
+    use rome_rowan::{AstNode, BatchMutationExt, Direction};
+
+    use super::{preferred_action, JsFormatSettings};
+    use crate::file_handlers::Language as LanguageId;
+    use crate::settings::{FormatSettings, Language, SettingsHandle, WorkspaceSettings};
+    use crate::workspace::{self, OpenFileParams, PullFixReportParams};
+    use std::sync::RwLock;
+
+    /// Creates an action renaming the first identifier of `root` to `name`
+    fn rename(root: &JsAnyRoot, name: &str) -> AnalyzerAction<JsLanguage> {
+        let token = root
+            .syntax()
+            .descendants_tokens(Direction::Next)
+            .find(|token| token.kind() == JsSyntaxKind::IDENT)
+            .unwrap();
+
+        let mut mutation = root.clone().begin();
+        mutation.replace_token(
+            token,
+            JsSyntaxToken::new_detached(JsSyntaxKind::IDENT, name, [], []),
+        );
+
+        AnalyzerAction {
+            group_name: "group",
+            rule_name: "rule",
+            file_id: FileId::zero(),
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::Always,
+            message: markup! { "message" }.to_owned(),
+            mutation,
+            span: None,
+        }
+    }
+
+    #[test]
+    fn preferred_action_is_shortest_valid() {
+        let root = rome_js_parser::parse("a;\n", FileId::zero(), SourceType::js_module()).tree();
+
+        let candidates = vec![
+            rename(&root, "aaaa"),
+            // Shortest output, but `1a;` is not valid JavaScript
+            rename(&root, "1a"),
+            rename(&root, "bbb"),
+            rename(&root, "ccc"),
+        ];
+
+        let options = JsFormatOptions::new(SourceType::js_module());
+        let action = preferred_action(candidates, FileId::zero(), &options).unwrap();
+
+        assert_eq!(action.mutation.commit().to_string(), "bbb;\n");
+    }
+
+    #[test]
+    fn pull_fix_report() {
+        let workspace = workspace::server();
+        let path = RomePath::new("file.js", FileId::zero());
+
+        workspace
+            .open_file(OpenFileParams {
+                path: path.clone(),
+                content: String::from("debugger;\n"),
+                version: 0,
+                language_hint: LanguageId::default(),
+            })
+            .unwrap();
+
+        let report = workspace
+            .pull_fix_report(PullFixReportParams { path })
+            .unwrap();
+
+        let entry = &report.entries()[0];
+        assert_eq!(entry.rule.as_deref(), Some("lint/correctness/noDebugger"));
+        assert_eq!(
+            entry.range,
+            Some(TextRange::new(TextSize::from(0), TextSize::from(9)))
+        );
+        assert_eq!(entry.fixes[0].message, "Remove debugger statement");
+        assert_eq!(entry.fixes[0].applicability, Applicability::MaybeIncorrect);
+        assert!(entry.fixes[0].edits.is_some());
+    }
+
+    #[test]
+    fn resolve_format_options_max_depth() {
+        let path = RomePath::new("file.js", FileId::zero());
+
+        let options = JsLanguage::resolve_format_options(
+            &FormatSettings::default(),
+            &JsFormatSettings::default(),
+            &path,
+        );
+        assert_eq!(options.max_depth(), 512);
+
+        let language = JsFormatSettings {
+            max_depth: Some(64),
+            ..JsFormatSettings::default()
+        };
+        let options =
+            JsLanguage::resolve_format_options(&FormatSettings::default(), &language, &path);
+        assert_eq!(options.max_depth(), 64);
+    }
+
+    #[test]
+    fn format_with_detected_indent_style() {
+        let path = RomePath::new("file.js", FileId::zero());
+        let source = "function f() {\n    return 1;\n}\n";
+
+        for (detect_indent_style, expected) in [
+            (false, "function f() {\n\treturn 1;\n}\n"),
+            (true, "function f() {\n    return 1;\n}\n"),
+        ] {
+            let mut settings = WorkspaceSettings::default();
+            settings.formatter.detect_indent_style = detect_indent_style;
+            let settings = RwLock::new(settings);
+
+            let parse = super::parse(&path, LanguageId::JavaScript, source);
+            let printed = super::format(&path, parse, SettingsHandle::new(&settings)).unwrap();
+
+            assert_eq!(printed.as_code(), expected);
+        }
+    }
 }
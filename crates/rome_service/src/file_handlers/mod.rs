@@ -8,7 +8,7 @@ use crate::{
     RomeError, Rules,
 };
 pub use javascript::JsFormatSettings;
-use rome_analyze::AnalysisFilter;
+use rome_analyze::{AnalysisFilter, FixReport};
 use rome_formatter::Printed;
 use rome_fs::RomePath;
 use rome_js_syntax::{TextRange, TextSize};
@@ -170,6 +170,8 @@ pub(crate) struct LintResults {
     pub(crate) diagnostics: Vec<rome_diagnostics::v2::serde::Diagnostic>,
     pub(crate) has_errors: bool,
     pub(crate) skipped_diagnostics: u64,
+    /// Number of diagnostics discarded because a suppression comment covers them
+    pub(crate) suppressed_diagnostics: u64,
 }
 
 type Lint = fn(LintParams) -> LintResults;
@@ -177,6 +179,7 @@ type CodeActions =
     fn(&RomePath, AnyParse, TextRange, Option<&Rules>, SettingsHandle) -> PullActionsResult;
 type FixAll = fn(FixAllParams) -> Result<FixFileResult, RomeError>;
 type Rename = fn(&RomePath, AnyParse, TextSize, String) -> Result<RenameResult, RomeError>;
+type PullFixReport = fn(&RomePath, AnyParse, Option<&Rules>, SettingsHandle) -> FixReport;
 
 #[derive(Default)]
 pub(crate) struct AnalyzerCapabilities {
@@ -188,6 +191,8 @@ pub(crate) struct AnalyzerCapabilities {
     pub(crate) fix_all: Option<FixAll>,
     /// It renames a binding inside a file
     pub(crate) rename: Option<Rename>,
+    /// It reports the diagnostics of a file along with their fixes
+    pub(crate) fix_report: Option<PullFixReport>,
 }
 
 type Format = fn(&RomePath, AnyParse, SettingsHandle) -> Result<Printed, RomeError>;
@@ -87,6 +87,16 @@ impl Matcher {
 
         matches
     }
+
+    /// It matches the given path against the stored patterns, without treating them
+    /// as plain substrings of the path like [Matcher::matches_path] does
+    ///
+    /// It returns [true] if there's at least a match
+    pub fn matches_glob_path(&self, source: &Path) -> bool {
+        self.patterns
+            .iter()
+            .any(|pattern| pattern.matches_path_with(source, self.options))
+    }
 }
 
 #[cfg(test)]
@@ -94,6 +104,7 @@ mod test {
     use crate::matcher::pattern::MatchOptions;
     use crate::matcher::Matcher;
     use std::env;
+    use std::path::Path;
 
     #[test]
     fn matches() {
@@ -133,4 +144,13 @@ mod test {
 
         assert!(result);
     }
+
+    #[test]
+    fn matches_glob_path_ignores_substrings() {
+        let mut matcher = Matcher::new(MatchOptions::default());
+        matcher.add_pattern("*/__tests__/*").unwrap();
+
+        assert!(matcher.matches_glob_path(Path::new("src/__tests__/index.js")));
+        assert!(!matcher.matches_glob_path(Path::new("src/my__tests__helper.js")));
+    }
 }
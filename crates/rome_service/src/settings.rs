@@ -2,12 +2,14 @@ use crate::{
     configuration::FilesConfiguration, Configuration, MatchOptions, Matcher, RomeError, Rules,
 };
 use indexmap::IndexSet;
+use rome_analyze::FileKind;
 use rome_diagnostics::v2::Category;
 use rome_formatter::{IndentStyle, LineWidth};
 use rome_fs::RomePath;
 use rome_js_syntax::JsLanguage;
 use std::{
     num::NonZeroU64,
+    path::Path,
     sync::{RwLock, RwLockReadGuard},
 };
 
@@ -98,6 +100,9 @@ pub struct FormatSettings {
     /// has syntax errors
     pub format_with_errors: bool,
     pub indent_style: Option<IndentStyle>,
+    /// Whether the indent style of each file is detected from its content,
+    /// with `indent_style` as the fallback
+    pub detect_indent_style: bool,
     pub line_width: Option<LineWidth>,
     /// List of paths/files to matcher
     pub ignored_files: Matcher,
@@ -109,6 +114,7 @@ impl Default for FormatSettings {
             enabled: true,
             format_with_errors: false,
             indent_style: Some(IndentStyle::default()),
+            detect_indent_style: false,
             line_width: Some(LineWidth::default()),
             ignored_files: Matcher::new(MatchOptions {
                 case_sensitive: true,
@@ -130,6 +136,45 @@ pub struct LinterSettings {
 
     /// List of paths/files to matcher
     pub ignored_files: Matcher,
+
+    /// Patterns of the paths of the test files, where some rules are relaxed
+    pub test_files: Matcher,
+}
+
+impl LinterSettings {
+    /// The patterns matching the usual locations of test files
+    const TEST_FILES: [&'static str; 6] = [
+        "*.test.*",
+        "*.spec.*",
+        "__tests__/*",
+        "*/__tests__/*",
+        "__mocks__/*",
+        "*/__mocks__/*",
+    ];
+
+    pub(crate) fn default_test_files() -> Matcher {
+        let mut matcher = Matcher::new(MatchOptions {
+            case_sensitive: true,
+            require_literal_leading_dot: false,
+            require_literal_separator: false,
+        });
+
+        for pattern in Self::TEST_FILES {
+            // SAFETY: The default patterns are valid
+            matcher.add_pattern(pattern).unwrap();
+        }
+
+        matcher
+    }
+
+    /// Returns the [kind](FileKind) of the file at `path`
+    pub fn file_kind(&self, path: &Path) -> FileKind {
+        if self.test_files.matches_glob_path(path) {
+            FileKind::Test
+        } else {
+            FileKind::Source
+        }
+    }
 }
 
 impl Default for LinterSettings {
@@ -142,6 +187,7 @@ impl Default for LinterSettings {
                 require_literal_leading_dot: false,
                 require_literal_separator: false,
             }),
+            test_files: Self::default_test_files(),
         }
     }
 }
@@ -249,3 +295,73 @@ impl<'a> SettingsHandle<'a> {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{FormatSettings, LinterSettings};
+    use crate::configuration::linter::LinterConfiguration;
+    use crate::configuration::FormatterConfiguration;
+    use crate::{ConfigurationError, RomeError};
+    use rome_analyze::FileKind;
+    use rome_formatter::IndentStyle;
+    use std::path::Path;
+
+    #[test]
+    fn file_kind_of_test_files() {
+        let settings = LinterSettings::default();
+
+        let cases = [
+            ("src/index.js", FileKind::Source),
+            ("src/contest.js", FileKind::Source),
+            ("src/index.test.js", FileKind::Test),
+            ("src/index.spec.tsx", FileKind::Test),
+            ("src/__tests__/index.js", FileKind::Test),
+            ("__tests__/index.js", FileKind::Test),
+            ("/home/user/project/src/__mocks__/fs.js", FileKind::Test),
+            ("src/my__tests__helper.js", FileKind::Source),
+        ];
+
+        for (path, kind) in cases {
+            assert_eq!(settings.file_kind(Path::new(path)), kind, "path: {path}");
+        }
+    }
+
+    #[test]
+    fn file_kind_of_configured_test_files() {
+        let configuration: LinterConfiguration =
+            serde_json::from_str(r#"{ "testFiles": ["*.e2e.js"] }"#).unwrap();
+        let settings = LinterSettings::try_from(configuration).unwrap();
+
+        let cases = [
+            ("src/index.js", FileKind::Source),
+            ("src/index.test.js", FileKind::Source),
+            ("e2e/login.e2e.js", FileKind::Test),
+        ];
+
+        for (path, kind) in cases {
+            assert_eq!(settings.file_kind(Path::new(path)), kind, "path: {path}");
+        }
+    }
+
+    #[test]
+    fn invalid_test_files_pattern() {
+        let configuration: LinterConfiguration =
+            serde_json::from_str(r#"{ "testFiles": ["***.test.js"] }"#).unwrap();
+        let error = LinterSettings::try_from(configuration).unwrap_err();
+
+        assert!(matches!(
+            error,
+            RomeError::Configuration(ConfigurationError::InvalidTestFilesPattern(..))
+        ));
+    }
+
+    #[test]
+    fn auto_indent_style_defaults_to_the_indent_size() {
+        let configuration: FormatterConfiguration =
+            serde_json::from_str(r#"{ "indentStyle": "auto", "indentSize": 4 }"#).unwrap();
+        let settings = FormatSettings::try_from(configuration).unwrap();
+
+        assert!(settings.detect_indent_style);
+        assert_eq!(settings.indent_style, Some(IndentStyle::Space(4)));
+    }
+}
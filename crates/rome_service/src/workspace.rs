@@ -52,8 +52,8 @@
 //! format a file with a language that does not have a formatter
 
 use crate::{Configuration, Deserialize, RomeError, Serialize};
-use rome_analyze::ActionCategory;
 pub use rome_analyze::RuleCategories;
+use rome_analyze::{ActionCategory, FixReport};
 use rome_console::{markup, Markup, MarkupBuf};
 use rome_diagnostics::{v2, CodeSuggestion};
 use rome_formatter::Printed;
@@ -186,6 +186,7 @@ pub struct PullDiagnosticsResult {
     pub diagnostics: Vec<v2::serde::Diagnostic>,
     pub has_errors: bool,
     pub skipped_diagnostics: u64,
+    pub suppressed_diagnostics: u64,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -209,6 +210,12 @@ pub struct CodeAction {
     pub suggestion: CodeSuggestion,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PullFixReportParams {
+    pub path: RomePath,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct FormatFileParams {
@@ -381,6 +388,10 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// position within a file
     fn pull_actions(&self, params: PullActionsParams) -> Result<PullActionsResult, RomeError>;
 
+    /// Retrieves the diagnostics emitted by the linter for a file along with
+    /// the fixes proposed by their actions
+    fn pull_fix_report(&self, params: PullFixReportParams) -> Result<FixReport, RomeError>;
+
     /// Runs the given file through the formatter using the provided options
     /// and returns the resulting source code
     fn format_file(&self, params: FormatFileParams) -> Result<Printed, RomeError>;
@@ -471,6 +482,12 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         })
     }
 
+    pub fn pull_fix_report(&self) -> Result<FixReport, RomeError> {
+        self.workspace.pull_fix_report(PullFixReportParams {
+            path: self.path.clone(),
+        })
+    }
+
     pub fn format_file(&self) -> Result<Printed, RomeError> {
         self.workspace.format_file(FormatFileParams {
             path: self.path.clone(),
@@ -3,6 +3,7 @@ use std::{
     sync::atomic::{AtomicU64, Ordering},
 };
 
+use rome_analyze::FixReport;
 use rome_formatter::Printed;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::json;
@@ -14,7 +15,7 @@ use super::{
     ChangeFileParams, CloseFileParams, FixFileParams, FixFileResult, FormatFileParams,
     FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
     GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, PullActionsParams, PullActionsResult,
-    PullDiagnosticsParams, PullDiagnosticsResult, RenameParams, RenameResult,
+    PullDiagnosticsParams, PullDiagnosticsResult, PullFixReportParams, RenameParams, RenameResult,
     SupportsFeatureParams, UpdateSettingsParams,
 };
 
@@ -151,6 +152,10 @@ where
         self.request("rome/pull_actions", params)
     }
 
+    fn pull_fix_report(&self, params: PullFixReportParams) -> Result<FixReport, RomeError> {
+        self.request("rome/pull_fix_report", params)
+    }
+
     fn format_file(&self, params: FormatFileParams) -> Result<Printed, RomeError> {
         self.request("rome/format_file", params)
     }
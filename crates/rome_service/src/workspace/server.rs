@@ -2,8 +2,8 @@ use super::{
     ChangeFileParams, CloseFileParams, FeatureName, FixFileResult, FormatFileParams,
     FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
     GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, PullActionsParams, PullActionsResult,
-    PullDiagnosticsParams, PullDiagnosticsResult, RenameResult, SupportsFeatureParams,
-    UpdateSettingsParams,
+    PullDiagnosticsParams, PullDiagnosticsResult, PullFixReportParams, RenameResult,
+    SupportsFeatureParams, UpdateSettingsParams,
 };
 use crate::file_handlers::{Capabilities, FixAllParams, Language, LintParams};
 use crate::workspace::{RageEntry, RageParams, RageResult, ServerInfo, SupportsFeatureResult};
@@ -14,7 +14,7 @@ use crate::{
 };
 use dashmap::{mapref::entry::Entry, DashMap};
 use indexmap::IndexSet;
-use rome_analyze::{AnalysisFilter, RuleFilter};
+use rome_analyze::{AnalysisFilter, FixReport, RuleFilter};
 use rome_diagnostics::v2::{serde::Diagnostic, DiagnosticExt};
 use rome_formatter::Printed;
 use rome_fs::RomePath;
@@ -399,6 +399,7 @@ impl Workspace for WorkspaceServer {
                 .collect(),
             has_errors: results.has_errors,
             skipped_diagnostics: results.skipped_diagnostics,
+            suppressed_diagnostics: results.suppressed_diagnostics,
         })
     }
 
@@ -423,6 +424,21 @@ impl Workspace for WorkspaceServer {
         ))
     }
 
+    /// Retrieves the diagnostics emitted by the linter for a file along with
+    /// the fixes proposed by their actions
+    fn pull_fix_report(&self, params: PullFixReportParams) -> Result<FixReport, RomeError> {
+        let capabilities = self.get_capabilities(&params.path);
+        let fix_report = capabilities
+            .analyzer
+            .fix_report
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone(), Some(FeatureName::Lint))?;
+        let settings = self.settings.read().unwrap();
+        let rules = settings.linter().rules.as_ref();
+        Ok(fix_report(&params.path, parse, rules, self.settings()))
+    }
+
     /// Runs the given file through the formatter using the provided options
     /// and returns the resulting source code
     fn format_file(&self, params: FormatFileParams) -> Result<Printed, RomeError> {
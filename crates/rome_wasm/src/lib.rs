@@ -4,7 +4,8 @@ use wasm_bindgen::prelude::*;
 use rome_service::workspace::{
     self, ChangeFileParams, CloseFileParams, FixFileParams, FormatFileParams, FormatOnTypeParams,
     FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams, GetSyntaxTreeParams,
-    PullActionsParams, PullDiagnosticsParams, RenameParams, UpdateSettingsParams,
+    PullActionsParams, PullDiagnosticsParams, PullFixReportParams, RenameParams,
+    UpdateSettingsParams,
 };
 use rome_service::workspace::{OpenFileParams, SupportsFeatureParams};
 
@@ -121,6 +122,15 @@ impl Workspace {
             .map_err(into_error)
     }
 
+    #[wasm_bindgen(js_name = pullFixReport)]
+    pub fn pull_fix_report(&self, params: IPullFixReportParams) -> Result<IFixReport, Error> {
+        let params: PullFixReportParams = params.into_serde().map_err(into_error)?;
+        let result = self.inner.pull_fix_report(params).map_err(into_error)?;
+        JsValue::from_serde(&result)
+            .map(IFixReport::from)
+            .map_err(into_error)
+    }
+
     #[wasm_bindgen(js_name = formatFile)]
     pub fn format_file(&self, params: IFormatFileParams) -> Result<JsValue, Error> {
         let params: FormatFileParams = params.into_serde().map_err(into_error)?;
@@ -68,7 +68,7 @@ export interface FormatterConfiguration {
 	 */
 	indentSize?: number;
 	/**
-	 * The indent style.
+	 * The indent style. `auto` detects the indentation of each file.
 	 */
 	indentStyle?: PlainIndentStyle;
 	/**
@@ -98,8 +98,12 @@ export interface LinterConfiguration {
 	 * List of rules
 	 */
 	rules?: Rules;
+	/**
+	 * A list of Unix shell style patterns matching the test files, where some rules are relaxed. Defaults to `*.test.*`, `*.spec.*` and the files inside `__tests__` and `__mocks__` directories.
+	 */
+	testFiles?: string[];
 }
-export type PlainIndentStyle = "tab" | "space";
+export type PlainIndentStyle = "tab" | "space" | "auto";
 /**
 	* Validated value for the `line_width` formatter options
 
@@ -212,11 +216,13 @@ export interface Nursery {
 	noConstAssign?: RuleConfiguration;
 	noExplicitAny?: RuleConfiguration;
 	noInvalidConstructorSuper?: RuleConfiguration;
+	noMissingInitializer?: RuleConfiguration;
 	/**
 	 * It enables the recommended rules for this group
 	 */
 	recommended?: boolean;
 	useCamelCase?: RuleConfiguration;
+	useConst?: RuleConfiguration;
 	useExhaustiveDependencies?: RuleConfiguration;
 	useFlatMap?: RuleConfiguration;
 	useValidForDirection?: RuleConfiguration;
@@ -308,6 +314,7 @@ export interface PullDiagnosticsResult {
 	diagnostics: Diagnostic[];
 	has_errors: boolean;
 	skipped_diagnostics: number;
+	suppressed_diagnostics: number;
 }
 /**
  * Serializable representation for a [Diagnostic](super::Diagnostic).
@@ -393,6 +400,8 @@ export type Category =
 	| "lint/nursery/useExhaustiveDependencies"
 	| "lint/nursery/useCamelCase"
 	| "lint/nursery/noBannedTypes"
+	| "lint/nursery/useConst"
+	| "lint/nursery/noMissingInitializer"
 	| "files/missingHandler"
 	| "format"
 	| "internalError/io"
@@ -401,6 +410,7 @@ export type Category =
 	| "lint"
 	| "parse"
 	| "parse/noSuperWithoutExtends"
+	| "suppressions/misplacedFileSuppression"
 	| "suppressions/unknownGroup"
 	| "suppressions/unknownRule"
 	| "args/fileNotFound"
@@ -515,7 +525,11 @@ export interface CodeAction {
 	rule_name: string;
 	suggestion: CodeSuggestion;
 }
-export type ActionCategory = "QuickFix" | "Refactor";
+export type ActionCategory =
+	| "QuickFix"
+	| "Refactor"
+	| "Suppression"
+	| "FileSuppression";
 /**
  * A Suggestion that is provided by rslint, and can be reported to the user, and can be automatically applied if it has the right [`Applicability`].
  */
@@ -540,6 +554,44 @@ export interface FileSpan {
 	file: FileId;
 	range: TextRange;
 }
+export interface PullFixReportParams {
+	path: RomePath;
+}
+/**
+	* Machine readable report of the diagnostics emitted by the analyzer and of the fixes proposed by their actions, for external tools that apply the fixes without going through the language server.
+
+The report serializes to a JSON array with an entry per diagnostic:
+
+```json [ { "rule": "lint/correctness/noDoubleEquals", "range": [4, 6], "message": "Use === instead of ==", "fixes": [ { "message": "Use ===", "applicability": "MaybeIncorrect", "edits": [{ "range": [4, 6], "text": "===" }] } ] } ] ``` 
+	 */
+export type FixReport = FixReportEntry[];
+export interface FixReportEntry {
+	fixes: FixReportFix[];
+	message: string;
+	/**
+	 * Range of the diagnostic in the original document
+	 */
+	range?: TextRange;
+	/**
+	 * Category of the diagnostic, for example `lint/correctness/noDoubleEquals`
+	 */
+	rule?: string;
+}
+export interface FixReportFix {
+	applicability: Applicability;
+	/**
+	 * Replacements to apply to the original document, sorted by position. Omitted if the edits of the fix couldn't be computed
+	 */
+	edits?: FixReportEdit[];
+	message: string;
+}
+export interface FixReportEdit {
+	/**
+	 * Range of the original document replaced by `text`
+	 */
+	range: TextRange;
+	text: string;
+}
 export interface FormatFileParams {
 	path: RomePath;
 }
@@ -632,6 +684,7 @@ export interface Workspace {
 		params: PullDiagnosticsParams,
 	): Promise<PullDiagnosticsResult>;
 	pullActions(params: PullActionsParams): Promise<PullActionsResult>;
+	pullFixReport(params: PullFixReportParams): Promise<FixReport>;
 	formatFile(params: FormatFileParams): Promise<Printed>;
 	formatRange(params: FormatRangeParams): Promise<Printed>;
 	formatOnType(params: FormatOnTypeParams): Promise<Printed>;
@@ -671,6 +724,9 @@ export function createWorkspace(transport: Transport): Workspace {
 		pullActions(params) {
 			return transport.request("rome/pull_actions", params);
 		},
+		pullFixReport(params) {
+			return transport.request("rome/pull_fix_report", params);
+		},
 		formatFile(params) {
 			return transport.request("rome/format_file", params);
 		},